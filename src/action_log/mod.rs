@@ -1,23 +1,17 @@
 extern crate termion;
 use termion::color::{Fg, Rgb};
 
+// My modules.
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
+// Initialized with two empty vectors.
+#[derive(Clone, Default)]
 pub struct ActionLog {
 
     actions: Vec<String>,
     is_error: Vec<bool>
 }
 
-// Initialized with two empty vectors.
-impl Default for ActionLog {
-    fn default() -> Self {
-        ActionLog {
-
-            actions: Vec::new(),
-            is_error: Vec::new()
-        }
-    }
-}
-
 impl ActionLog {
     
     // PushAction Function:
@@ -37,25 +31,56 @@ impl ActionLog {
         }
     }
 
-    // PrintIndex Function:
+    // FormatIndex Function:
     // Parameters:
-    // - index: The index to print from on the action log.
-    // Description: Prints the element at the given index. Red if its an error. Green otherwise.
-    pub fn PrintIndex(&self, index: usize) {
+    // - index: The index to display from on the action log.
+    // Description: Formats the element at the given index. Red if its an error. Green otherwise.
+    // Return: The colored message. Empty if there is no message at the index.
+    pub fn FormatIndex(&self, index: usize) -> String {
 
         if index >= self.actions.len() {
 
-            return;
+            return String::new();
         }
 
         // Determine color of message.
-        let mut col = Fg(Rgb(65, 221, 68));;
+        let mut col = Fg(Rgb(65, 221, 68));
         if self.is_error[index] {
 
             col = Fg(Rgb(228, 8, 10));
         }
 
-        print!("{}{}{}", col, self.actions[index], Fg(Rgb(255, 255, 255)));
+        return format!("{}{}{}", col, self.actions[index], Fg(Rgb(255, 255, 255)));
     }
-}
 
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the number of messages followed by each message and its error flag.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("log", &[self.actions.len().ToField()]);
+        for i in 0..self.actions.len() {
+
+            writer.Write("message", &[self.is_error[i].ToField(), self.actions[i].ToField()]);
+        }
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a log written by Save.
+    pub fn Load(reader: &mut SaveReader) -> Result<ActionLog, SaveError> {
+
+        let mut log = ActionLog::default();
+
+        let length: usize = reader.Read("log")?.Get(0)?;
+        for _ in 0..length {
+
+            let record = reader.Read("message")?;
+            log.PushAction(record.Get(1)?, record.Get(0)?);
+        }
+
+        return Ok(log);
+    }
+}
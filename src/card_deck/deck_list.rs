@@ -0,0 +1,185 @@
+// Std modules.
+use std::fs;
+
+// My modules.
+use super::Card;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
+/*
+Deck List File Format:
+- Description: A deck list is plain text with one atom per line. Each line holds the name of an atom followed by the
+  number of copies in the deck. Blank lines and lines starting with # are skipped.
+- Only atoms and polyatomic ions that Card::FromName knows can be listed, and each one can only be listed once.
+- Example:
+  # Standard deck.
+  H 4
+  O 4
+*/
+
+// Struct holding the atoms in a deck and how many copies of each.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeckList {
+
+    entries: Vec<(String, u8)>
+}
+
+// default Function:
+// - Description: The standard deck. Four hydrogens and oxygens, two lithium, beryllium, and carbon, and one nitrogen
+//   and flourine.
+impl Default for DeckList {
+    fn default() -> Self {
+
+        return DeckList {
+
+            entries: vec![
+                (String::from("H"), 4), (String::from("O"), 4),
+                (String::from("Li"), 2), (String::from("Be"), 2), (String::from("C"), 2),
+                (String::from("N"), 1), (String::from("F"), 1)
+            ]
+        };
+    }
+}
+
+impl DeckList {
+
+    // GetCards Function:
+    // Description: Returns every card in the deck list.
+    pub fn GetCards(&self) -> Vec<Card> {
+
+        let mut cards: Vec<Card> = Vec::new();
+        for (name, count) in self.entries.iter() {
+
+            // Names are checked when the list is made, so every name has a card.
+            let card = Card::FromName(name).unwrap();
+            for _ in 0..*count {
+
+                cards.push(card.clone());
+            }
+        }
+
+        return cards;
+    }
+
+    // HasTransitionMetals Function:
+    // Description: Returns true if any atom in the deck list is a transition metal.
+    pub fn HasTransitionMetals(&self) -> bool {
+
+        return self.GetCards().iter().any(|card| card.IsTransitionMetal());
+    }
+
+    // Add Function:
+    // Parameters:
+    // - name: Name of the atom.
+    // - count: Number of copies.
+    // Description: Adds an atom to the deck list.
+    // Return: Error message if the atom cannot be put into a deck or is already listed.
+    fn Add(&mut self, name: &str, count: u8) -> Result<(), String> {
+
+        if Card::FromName(name) == None {
+
+            return Err(format!("\"{}\" is not an atom that can be put into a deck.", name));
+        }
+
+        if count == 0 {
+
+            return Err(format!("{} must have at least one copy.", name));
+        }
+
+        if self.entries.iter().any(|(listed, _)| listed == name) {
+
+            return Err(format!("{} is listed more than once.", name));
+        }
+
+        self.entries.push((String::from(name), count));
+        return Ok(());
+    }
+
+    // LoadFile Function:
+    // Parameters:
+    // - path: The deck list file to read.
+    // Description: Reads a deck list written in the format above.
+    // Return: The deck list. Error pointing at the first line that could not be used.
+    pub fn LoadFile(path: &str) -> Result<DeckList, SaveError> {
+
+        let text = match fs::read_to_string(path) {
+
+            Ok(text) => text,
+            Err(error) => return Err(SaveError::Io(format!("Could not read \"{}\". {}", path, error))),
+        };
+
+        let mut list = DeckList { entries: Vec::new() };
+        for (index, line) in text.lines().enumerate() {
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let error = |message: String| SaveError::Format { line: index + 1, message };
+
+            if fields.len() != 2 {
+
+                return Err(error(String::from("Expected an atom followed by the number of copies.")));
+            }
+
+            let count = match u8::FromField(fields[1]) {
+
+                Some(count) => count,
+                None => return Err(error(format!("\"{}\" is not a number of copies.", fields[1]))),
+            };
+
+            match list.Add(fields[0], count) {
+
+                Ok(_) => (),
+                Err(message) => return Err(error(message)),
+            }
+        }
+
+        if list.entries.is_empty() {
+
+            return Err(SaveError::Io(format!("\"{}\" does not list any atoms.", path)));
+        }
+
+        return Ok(list);
+    }
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the number of atoms followed by each atom and its number of copies.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("deck_list", &[self.entries.len().ToField()]);
+        for (name, count) in self.entries.iter() {
+
+            writer.Write("entry", &[name.ToField(), count.ToField()]);
+        }
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a deck list written by Save.
+    // Return: The deck list. Error if an atom cannot be put into a deck.
+    pub fn Load(reader: &mut SaveReader) -> Result<DeckList, SaveError> {
+
+        let mut list = DeckList { entries: Vec::new() };
+
+        let length: usize = reader.Read("deck_list")?.Get(0)?;
+        for _ in 0..length {
+
+            let record = reader.Read("entry")?;
+            let name: String = record.Get(0)?;
+
+            match list.Add(&name, record.Get(1)?) {
+
+                Ok(_) => (),
+                Err(message) => return Err(record.Error(&message)),
+            }
+        }
+
+        return Ok(list);
+    }
+}
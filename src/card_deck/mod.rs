@@ -1,5 +1,12 @@
-extern crate rand;
-use rand::Rng;
+// My modules.
+use crate::chance::{Chance, Stream};
+use crate::element_table::{Element, Ion};
+use crate::game_board::PlayedAtom;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+mod deck_list;
+pub use deck_list::DeckList;
+#[cfg(test)]
+mod tests;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Card {
@@ -11,90 +18,213 @@ pub struct Card {
     pub is_metal: bool,
     pub atomic_number: u16,
 
-    // Evolution data members.
-    evo_name: String,
-    evo_symbol: String,
-    evo_atomic_number: u16
+    // True for polyatomic ions, which can only form ionic bonds. Positive ions are flagged as metals, since they bond
+    // the same way metals do. The atomic number of an ion is the sum of the atoms within it.
+    pub is_ion: bool,
+
+    // Electronegativity times one hundred, used to work out the type of each bond. None for polyatomic ions, which
+    // always bond ionically, and elements with no Pauling value.
+    pub electronegativity: Option<u16>,
+
+    // Name of the deck list card this card was dealt as. Only differs from name once the card has been evolved.
+    pub origin: String
 }
 
 impl Card {
 
-    // When evolved, an atom's evo fields become the main feilds.
-    pub fn Evolve(&mut self) {
+    // FromName Function:
+    // Parameters:
+    // - name: Symbol of an element. (Example: Li)
+    // Description: Creates the card for an atom or polyatomic ion that can be put into a deck. Noble gases become
+    //   inert cards which cannot bond.
+    // Return: None if the atom cannot be put into a deck.
+    pub fn FromName(name: &str) -> Option<Card> {
+
+        match Element::Find(name) {
 
-        self.name = self.evo_name.clone();
-        self.symbol = self.evo_symbol.clone();
-        self.atomic_number = self.evo_atomic_number.clone();
+            Some(element) => return Some(Card::FromElement(element)),
+            None => return Ion::Find(name).map(Card::FromIon),
+        }
     }
-}
 
-pub struct CardDeck {
+    // FromElement Function:
+    // Parameters:
+    // - element: Entry of the element table.
+    // Description: Creates the card for an element.
+    fn FromElement(element: &Element) -> Card {
+
+        return Card {
+
+            name: String::from(element.symbol),
+            symbol: format!("{:<2}", element.symbol),
+            number_of_bonds: element.GetBondingCapacity(),
+            is_metal: element.is_metal,
+            atomic_number: element.atomic_number,
+            is_ion: false,
+            electronegativity: element.electronegativity,
+            origin: String::from(element.symbol)
+        };
+    }
 
-    deck: Vec<Card>,
-    hand: Vec<Card>
-}
+    // FromIon Function:
+    // Parameters:
+    // - ion: Entry of the ion table.
+    // Description: Creates the card for a polyatomic ion. The name is the formula of the ion.
+    fn FromIon(ion: &Ion) -> Card {
+
+        return Card {
+
+            name: String::from(ion.formula),
+            symbol: ion.GetSymbol(),
+            number_of_bonds: ion.GetBondingCapacity(),
+            is_metal: ion.IsCation(),
+            atomic_number: ion.GetAtomicSum(),
+            is_ion: true,
+            electronegativity: None,
+            origin: String::from(ion.formula)
+        };
+    }
 
-impl Default for CardDeck {
+    // GetBondOptions Function:
+    // Description: Returns every number of bonds the atom can be played with. Transition metals have one for each
+    //   oxidation state, and other atoms only have one.
+    pub fn GetBondOptions(&self) -> Vec<u16> {
 
-    fn default() -> Self {
+        match Element::Find(&self.name) {
 
-        // Create card structs which will be used to initialize the deck.
-        let hydrogen = Card{name: String::from("H"), symbol: String::from("H "), number_of_bonds: 1, 
-        is_metal: false, atomic_number: 1, evo_name: String::from("Li"), evo_symbol: String::from("Li"), evo_atomic_number: 3};
+            Some(element) => return element.GetBondOptions(),
+            None => return vec![self.number_of_bonds],
+        }
+    }
 
-        let lithium = Card{name: String::from("Li"), symbol: String::from("Li"), number_of_bonds: 1, 
-        is_metal: true, atomic_number: 3, evo_name: String::from("Na"), evo_symbol: String::from("Na"), evo_atomic_number: 11};
+    // GetAtom Function:
+    // Parameters:
+    // - bond_number: Number of bonds the atom is played with. One of the options from GetBondOptions.
+    // Description: Returns the atom that is put onto the board when the card is played.
+    pub fn GetAtom(&self, bond_number: u16) -> PlayedAtom {
+
+        return PlayedAtom {
+
+            symbol: self.symbol.clone(),
+            bond_number,
+            is_metal: self.is_metal,
+            is_ion: self.is_ion,
+            electronegativity: self.electronegativity,
+            atomic_number: self.atomic_number
+        };
+    }
 
-        let beryllium = Card{name: String::from("Be"), symbol: String::from("Be"), number_of_bonds: 2, 
-        is_metal: true, atomic_number: 4, evo_name: String::from("Mg"), evo_symbol: String::from("Mg"), evo_atomic_number: 12};
+    // IsTransitionMetal Function:
+    // Description: Returns true if the atom is a transition metal.
+    pub fn IsTransitionMetal(&self) -> bool {
 
-        let carbon = Card{name: String::from("C"), symbol: String::from("C "), number_of_bonds: 4, 
-        is_metal: false, atomic_number: 6, evo_name: String::from("Si"), evo_symbol: String::from("Si"), evo_atomic_number: 14};
+        return Element::Find(&self.name).is_some_and(|element| element.IsTransitionMetal());
+    }
 
-        let nitrogen = Card{name: String::from("N"), symbol: String::from("N "), number_of_bonds: 3, 
-        is_metal: false, atomic_number: 7, evo_name: String::from("P"), evo_symbol: String::from("P "), evo_atomic_number: 15};
+    // CanEvolve Function:
+    // Description: Returns true if there is an element for the atom to evolve into.
+    pub fn CanEvolve(&self) -> bool {
 
-        let oxygen = Card{name: String::from("O"), symbol: String::from("O "), number_of_bonds:2, 
-        is_metal: false, atomic_number: 8, evo_name: String::from("S"), evo_symbol: String::from("S "), evo_atomic_number: 16};
+        return Element::Find(&self.name).and_then(|element| element.GetEvolution()) != None;
+    }
 
-        let flourine = Card{name: String::from("F"), symbol: String::from("F "), number_of_bonds: 1, is_metal: false,atomic_number: 9, evo_name: String::from("Cl"), evo_symbol: String::from("Cl"), evo_atomic_number: 17};
+    // Evolve Function:
+    // Description: Turns the atom into the element below it within the same group. The card keeps its origin.
+    // Return: False if the atom cannot evolve, in which case it does not change.
+    pub fn Evolve(&mut self) -> bool {
 
-        let mut card_vector: Vec<Card> = Vec::new();
+        match Element::Find(&self.name).and_then(|element| element.GetEvolution()) {
 
-        // Add four hydrogens and four oxygens.
-        for i in 0..4 {
+            Some(evolution) => {
 
-            card_vector.push(hydrogen.clone());
-            card_vector.push(oxygen.clone());
+                let origin = self.origin.clone();
+                *self = Card::FromElement(evolution);
+                self.origin = origin;
+                return true;
+            },
+            None => return false,
         }
+    }
 
-        // Two lithium, beryllium, and carbon.
-        for i in 0..2 {
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the card as one record.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("card", &[
+            self.name.ToField(),
+            self.symbol.ToField(),
+            self.number_of_bonds.ToField(),
+            self.is_metal.ToField(),
+            self.atomic_number.ToField(),
+            self.is_ion.ToField(),
+            self.electronegativity.ToField(),
+            self.origin.ToField()
+        ]);
+    }
 
-            card_vector.push(lithium.clone());
-            card_vector.push(beryllium.clone());
-            card_vector.push(carbon.clone());
-        }
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a card written by Save.
+    pub fn Load(reader: &mut SaveReader) -> Result<Card, SaveError> {
+
+        let record = reader.Read("card")?;
+
+        return Ok(Card {
+
+            name: record.Get(0)?,
+            symbol: record.Get(1)?,
+            number_of_bonds: record.Get(2)?,
+            is_metal: record.Get(3)?,
+            atomic_number: record.Get(4)?,
+            is_ion: record.Get(5)?,
+            electronegativity: record.Get(6)?,
+            origin: record.Get(7)?
+        });
+    }
+}
 
-        // One nitrigen and flourine.
-        card_vector.push(nitrogen.clone());
-        card_vector.push(flourine.clone());
+#[derive(Clone)]
+pub struct CardDeck {
 
-        CardDeck {
-            
-            deck: card_vector,
-            hand: Vec::new()
-        }
+    deck: Vec<Card>,
+    hand: Vec<Card>
+}
+
+// default Function:
+// - Description: Creates a deck from the standard deck list.
+impl Default for CardDeck {
+
+    fn default() -> Self {
+
+        return CardDeck::New(&DeckList::default());
     }
 }
 
 impl CardDeck {
-    
+
+    // New Function:
+    // Parameters:
+    // - list: The atoms in the deck and how many of each.
+    // Description: Creates a deck holding the cards of a deck list with an empty hand.
+    pub fn New(list: &DeckList) -> CardDeck {
+
+        return CardDeck {
+
+            deck: list.GetCards(),
+            hand: Vec::new()
+        };
+    }
+
     // AddToHand Function:
+    // Parameters:
+    // - chance: Source of the random draw.
     // Description: Moves a card from the deck vector into the hand vector.
-    pub fn AddToHand(&mut self) {
+    pub fn AddToHand(&mut self, chance: &mut Chance) {
 
-        match self.DrawCard() {
+        match self.DrawCard(chance) {
             
             Some(card) => {
                 
@@ -105,21 +235,20 @@ impl CardDeck {
     }
 
     // DrawCard Function:
+    // Parameters:
+    // - chance: Source of the random draw.
     // Description: Returns a card if there are cards remaining in the deck. Removes the returned value from the deck.
     // Return: Card representing an atom within and option.
-    fn DrawCard(&mut self) -> Option<Card> {
+    fn DrawCard(&mut self, chance: &mut Chance) -> Option<Card> {
 
         // In case of an empty deck.
-        if self.deck.len() == 0 {
+        if self.deck.is_empty() {
 
             return None;
         }
 
-        // Initialize random number generator.
-        let mut random = rand::thread_rng();
-
         // Generate a random index and use it to choose a card.
-        let index: usize = random.gen_range(0..self.deck.len());
+        let index: usize = chance.Pick(Stream::Draw, self.deck.len());
         let ret_val = self.deck[index].clone();
 
         // Remove the chosen card from the struct.
@@ -128,11 +257,54 @@ impl CardDeck {
         return Some(ret_val);
     }
 
+    // Resample Function:
+    // Parameters:
+    // - list: The deck list this deck was made from.
+    // - played: Names of the cards from this deck that have been seen on the board.
+    // - chance: Source of the random draws.
+    // Description: Replaces the hand and deck with a random guess made from the unseen cards. Used by the computer
+    //   to guess at cards that it cannot see.
+    pub fn Resample(&mut self, list: &DeckList, played: &[String], chance: &mut Chance) {
+
+        let hand_size = self.hand.len();
+
+        self.deck = CardDeck::Unseen(list, played);
+        self.hand = Vec::new();
+
+        for _ in 0..hand_size {
+
+            self.AddToHand(chance);
+        }
+    }
+
+    // Unseen Function:
+    // Parameters:
+    // - list: The deck list the deck was made from.
+    // - played: Deck list names of the cards from a deck that have been seen on the board. Evolved cards are named by
+    //   the card they were dealt as.
+    // Description: Returns the cards of the deck list minus the cards that have been played. These are the cards
+    //   that the other side could still be holding or drawing.
+    pub fn Unseen(list: &DeckList, played: &[String]) -> Vec<Card> {
+
+        let mut unseen = list.GetCards();
+
+        for name in played {
+
+            match unseen.iter().position(|card| card.name == *name) {
+
+                Some(index) => { unseen.remove(index); },
+                None => (),
+            }
+        }
+
+        return unseen;
+    }
+
     // GetCard Function:
     // Parameters:
     // - card_name: Name of the atom displayed on the card.
     // Description: Returns a card based on the name of the card proveided. Returns None if the name is invalid.
-    pub fn GetCard(&mut self, card_name: String) -> Option<Card> {
+    pub fn GetCard(&self, card_name: String) -> Option<Card> {
 
         for i in 0..self.hand.len() {
 
@@ -149,23 +321,30 @@ impl CardDeck {
     // Parameters:
     // - card_index: Index of the atom displayed on the card.
     // Description: Returns a card based on the index of the card proveided.
-    pub fn GetFromIndex(&mut self, card_index: usize) -> Card {
+    pub fn GetFromIndex(&self, card_index: usize) -> Card {
 
         return self.hand[card_index].clone();
     }
 
+    // GetHandSize Function:
+    // Description: Returns the number of cards in hand.
+    pub fn GetHandSize(&self) -> usize {
+
+        return self.hand.len();
+    }
+
     // EvolveCard Function:
     // Parameters:
     // - card_name: Name of the card to evolve in hand.
     // Description: Evolves a card in hand based on its name.
+    // Return: False if the card is not in hand or cannot evolve.
     pub fn EvolveCard(&mut self, card_name: String) -> bool {
 
         for i in 0..self.hand.len() {
 
             if self.hand[i].name == card_name {
 
-                self.hand[i].Evolve();
-                return true
+                return self.hand[i].Evolve();
             }
         }
 
@@ -188,34 +367,80 @@ impl CardDeck {
         }
     }
 
-    // PrintUpperHand and PrintLowerHand Functions:
-    // Description: Cards are displayed across two lines. Each function prints one line of the display.
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the cards left in the deck followed by the cards in hand. Each list starts with its length.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("deck", &[self.deck.len().ToField()]);
+        for card in self.deck.iter() {
+
+            card.Save(writer);
+        }
+
+        writer.Write("hand", &[self.hand.len().ToField()]);
+        for card in self.hand.iter() {
+
+            card.Save(writer);
+        }
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a deck and hand written by Save.
+    pub fn Load(reader: &mut SaveReader) -> Result<CardDeck, SaveError> {
+
+        let mut deck: Vec<Card> = Vec::new();
+        let deck_size: usize = reader.Read("deck")?.Get(0)?;
+        for _ in 0..deck_size {
+
+            deck.push(Card::Load(reader)?);
+        }
+
+        let mut hand: Vec<Card> = Vec::new();
+        let hand_size: usize = reader.Read("hand")?.Get(0)?;
+        for _ in 0..hand_size {
+
+            hand.push(Card::Load(reader)?);
+        }
+
+        return Ok(CardDeck { deck, hand });
+    }
+
+    // FormatUpperHand and FormatLowerHand Functions:
+    // Description: Cards are displayed across two lines. Each function returns one line of the display.
     //---------------------------------------------------------------------------------------------------
-    pub fn PrintUpperHand(&mut self) {
+    pub fn FormatUpperHand(&self) -> String {
 
+        let mut line = String::new();
         for i in 0..self.hand.len() {
 
-            print!("|{}", self.hand[i].symbol);
+            line.push_str(&format!("|{}", self.hand[i].symbol));
         }
 
-        print!("|");
+        line.push('|');
+        return line;
     }
 
-    pub fn PrintLowerHand(&mut self) {
+    pub fn FormatLowerHand(&self) -> String {
 
+        let mut line = String::new();
         for i in 0..self.hand.len() {
 
             if self.hand[i].atomic_number < 10 {
                 
-                print!("|{} ", self.hand[i].atomic_number);
+                line.push_str(&format!("|{} ", self.hand[i].atomic_number));
             }
             else {
 
-                print!("|{}", self.hand[i].atomic_number);
+                line.push_str(&format!("|{}", self.hand[i].atomic_number));
             }
         }
 
-        print!("|");
+        line.push('|');
+        return line;
     }
     //---------------------------------------------------------------------------------------------------
 }
\ No newline at end of file
@@ -0,0 +1,142 @@
+// Std modules.
+use std::env;
+use std::fs;
+
+// My modules.
+use super::{Card, CardDeck, DeckList};
+use crate::chance::Chance;
+use crate::save_file::SaveError;
+
+// WriteTemp Function:
+// Parameters:
+// - name: Name of the file, which should differ between tests since they run at the same time.
+// - text: Text written to the file.
+// Description: Writes a file within the temporary directory.
+// Return: Path of the file.
+fn WriteTemp(name: &str, text: &str) -> String {
+
+    let path = env::temp_dir().join(format!("fcgreen6-{}-{}", std::process::id(), name)).to_string_lossy().into_owned();
+    fs::write(&path, text).unwrap();
+    return path;
+}
+
+// Names Function:
+// Parameters:
+// - cards: The cards to name.
+// Description: Returns the names of the cards in sorted order, so that two piles of cards can be compared.
+fn Names(cards: &[Card]) -> Vec<String> {
+
+    let mut names: Vec<String> = cards.iter().map(|card| card.name.clone()).collect();
+    names.sort();
+    return names;
+}
+
+// ListError Function:
+// Parameters:
+// - name: Name of the temporary deck list file.
+// - text: Text of the deck list.
+// Description: Reads a deck list that should not be allowed.
+// Return: Line and message of the error.
+fn ListError(name: &str, text: &str) -> (usize, String) {
+
+    match DeckList::LoadFile(&WriteTemp(name, text)) {
+
+        Err(SaveError::Format { line, message }) => return (line, message),
+        other => panic!("Expected a format error but got {:?}.", other),
+    }
+}
+
+#[test]
+fn DeckListFileListsEveryCopy() {
+
+    let text = "# Standard deck.\nH 4\nO 4\n\nLi 2\nBe 2\nC 2\n  N 1\nF 1\n";
+    let list = DeckList::LoadFile(&WriteTemp("list-standard.txt", text)).unwrap();
+    assert_eq!(list, DeckList::default());
+
+    let list = DeckList::LoadFile(&WriteTemp("list-ions.txt", "OH 3\nNe 1\n")).unwrap();
+    let names: Vec<String> = list.GetCards().into_iter().map(|card| card.name).collect();
+    assert_eq!(names, ["OH", "OH", "OH", "Ne"]);
+    assert!(!list.HasTransitionMetals());
+}
+
+#[test]
+fn DeckListErrorsNameTheLine() {
+
+    assert_eq!(ListError("list-unknown.txt", "H 4\nXx 2\n"), (2, String::from("\"Xx\" is not an atom that can be put into a deck.")));
+    assert_eq!(ListError("list-count.txt", "H four\n"), (1, String::from("\"four\" is not a number of copies.")));
+    assert_eq!(ListError("list-negative.txt", "# Comment.\nH -1\n"), (2, String::from("\"-1\" is not a number of copies.")));
+    assert_eq!(ListError("list-zero.txt", "H 0\n"), (1, String::from("H must have at least one copy.")));
+    assert_eq!(ListError("list-twice.txt", "H 4\nO 4\nH 1\n"), (3, String::from("H is listed more than once.")));
+    assert_eq!(ListError("list-fields.txt", "H 4 extra\n"), (1, String::from("Expected an atom followed by the number of copies.")));
+    assert_eq!(ListError("list-alone.txt", "H\n"), (1, String::from("Expected an atom followed by the number of copies.")));
+}
+
+#[test]
+fn EmptyDeckListIsAnError() {
+
+    let path = WriteTemp("list-empty.txt", "# Nothing here.\n\n");
+    assert_eq!(DeckList::LoadFile(&path), Err(SaveError::Io(format!("\"{}\" does not list any atoms.", path))));
+
+    let path = WriteTemp("list-blank.txt", "");
+    assert!(matches!(DeckList::LoadFile(&path), Err(SaveError::Io(_))));
+}
+
+#[test]
+fn EvolvedCardKeepsItsOrigin() {
+
+    let mut card = Card::FromName("C").unwrap();
+    assert!(card.Evolve());
+    assert_eq!((card.name.as_str(), card.atomic_number, card.origin.as_str()), ("Si", 14, "C"));
+    assert!(card.Evolve());
+    assert_eq!((card.name.as_str(), card.atomic_number, card.origin.as_str()), ("Ge", 32, "C"));
+
+    // Nothing is below germanium, so the card does not change.
+    let bottom = card.clone();
+    assert!(!card.CanEvolve());
+    assert!(!card.Evolve());
+    assert!(card == bottom);
+}
+
+#[test]
+fn UnseenLeavesOutPlayedCards() {
+
+    // One copy is taken out for each card played, and names that are not in the list are skipped.
+    let played = [String::from("H"), String::from("H"), String::from("C"), String::from("N"), String::from("Ne")];
+    let unseen = CardDeck::Unseen(&DeckList::default(), &played);
+
+    assert_eq!(unseen.len(), 12);
+    assert_eq!(unseen.iter().filter(|card| card.name == "H").count(), 2);
+    assert_eq!(unseen.iter().filter(|card| card.name == "C").count(), 1);
+    assert!(unseen.iter().all(|card| card.name != "N"));
+}
+
+#[test]
+fn ResampleOnlyUsesUnseenCards() {
+
+    let list = DeckList::default();
+    let played = [String::from("O"), String::from("Li"), String::from("F")];
+
+    // Two decks with different hands.
+    let mut first = CardDeck::New(&list);
+    let mut second = CardDeck::New(&list);
+    let (mut first_chance, mut second_chance) = (Chance::Seeded(1), Chance::Seeded(2));
+    for _ in 0..5 {
+
+        first.AddToHand(&mut first_chance);
+        second.AddToHand(&mut second_chance);
+    }
+    assert!(Names(&first.hand) != Names(&second.hand));
+
+    first.Resample(&list, &played, &mut Chance::Seeded(3));
+    second.Resample(&list, &played, &mut Chance::Seeded(3));
+
+    // The guess keeps the size of the hand and is made only from cards that have not been played.
+    assert_eq!(first.GetHandSize(), 5);
+    let mut pile = first.hand.clone();
+    pile.extend(first.deck.clone());
+    assert_eq!(Names(&pile), Names(&CardDeck::Unseen(&list, &played)));
+
+    // The cards that were really held do not change the guess.
+    assert_eq!(Names(&first.hand), Names(&second.hand));
+    assert_eq!(Names(&first.deck), Names(&second.deck));
+}
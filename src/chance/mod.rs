@@ -0,0 +1,162 @@
+// Rng crate.
+extern crate rand;
+use rand::{Rng, RngCore, SeedableRng};
+use rand::rngs::StdRng;
+
+// Std modules.
+use std::collections::VecDeque;
+
+// Enum naming what a random outcome was picked for. Outcomes of one kind are only given back to picks of the same
+// kind after an undo, so that a play which picks a different number of colors cannot change which cards are drawn.
+// - Coin: Which side goes first.
+// - Draw: A card drawn from a deck.
+// - Color: The color of a new compound.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stream {
+    Coin,
+    Draw,
+    Color,
+}
+
+// Source of every random outcome within a game. Outcomes come from a single generator made from a seed, so a game
+// dealt from the same seed with the same actions plays out the same way. Each outcome is also recorded, so that a
+// replay can feed the same outcomes back in the same order.
+#[derive(Clone)]
+pub struct Chance {
+
+    // Seed the generator was made from, and the generator itself.
+    seed: u64,
+    random: StdRng,
+
+    // Outcomes in the order they were picked, and what each was picked for. Outcomes given to Resume were picked
+    // before the game was saved, so what they were picked for is not known.
+    outcomes: Vec<usize>,
+    streams: Vec<Option<Stream>>,
+
+    // Recorded outcomes that have not been used yet. These are used before any new random outcome. Outcomes from a
+    // replay are not tagged with a stream and are used by whichever pick comes next.
+    queued: VecDeque<(Option<Stream>, usize)>
+}
+
+// default Function:
+// - Description: Creates a source with a random seed.
+impl Default for Chance {
+    fn default() -> Self {
+
+        return Chance::Seeded(rand::thread_rng().gen());
+    }
+}
+
+impl Chance {
+
+    // Seeded Function:
+    // Parameters:
+    // - seed: Number the generator is made from.
+    // Description: Creates a source whose outcomes are decided by the seed.
+    pub fn Seeded(seed: u64) -> Chance {
+
+        return Chance {
+
+            seed,
+            random: StdRng::seed_from_u64(seed),
+            outcomes: Vec::new(),
+            streams: Vec::new(),
+            queued: VecDeque::new()
+        };
+    }
+
+    // FromOutcomes Function:
+    // Parameters:
+    // - seed: Seed of the game that recorded the outcomes.
+    // - outcomes: Outcomes recorded by another game.
+    // Description: Creates a source which gives back the recorded outcomes in order. Once they run out, new
+    //   outcomes continue from the seed as if they had been picked by the generator.
+    pub fn FromOutcomes(seed: u64, outcomes: Vec<usize>) -> Chance {
+
+        let mut chance = Chance::Seeded(seed);
+        chance.queued = outcomes.into_iter().map(|outcome| (None, outcome)).collect();
+
+        return chance;
+    }
+
+    // Resume Function:
+    // Parameters:
+    // - seed: Seed of a saved game.
+    // - outcomes: Outcomes already picked by the saved game.
+    // Description: Creates a source which continues recording after the given outcomes. The generator is moved past
+    //   the outcomes so that the saved game continues exactly as it would have.
+    pub fn Resume(seed: u64, outcomes: Vec<usize>) -> Chance {
+
+        let mut chance = Chance::Seeded(seed);
+        for _ in 0..outcomes.len() {
+
+            chance.random.next_u64();
+        }
+        chance.streams = vec![None; outcomes.len()];
+        chance.outcomes = outcomes;
+
+        return chance;
+    }
+
+    // Pick Function:
+    // Parameters:
+    // - stream: What the outcome is picked for.
+    // - range: Number of possible outcomes.
+    // Description: Picks a number from 0 up to but not including range and records it. The first queued outcome of
+    //   the same stream is used if there is one. A queued outcome that does not fit within range is thrown out and
+    //   replaced with a new one.
+    pub fn Pick(&mut self, stream: Stream, range: usize) -> usize {
+
+        // Every pick moves the generator forward by exactly one number, even when a queued outcome is used. This keeps
+        // the generator in step with the outcomes, which is what Resume and Rewind rely on. Ranges are small, so the
+        // bias from the remainder is too small to matter.
+        let generated = (self.random.next_u64() % range as u64) as usize;
+
+        let position = self.queued.iter().position(|queued| (queued.0 == None) || (queued.0 == Some(stream)));
+        let outcome = match position.and_then(|index| self.queued.remove(index)) {
+
+            Some((_, queued)) if queued < range => queued,
+            _ => generated,
+        };
+
+        self.outcomes.push(outcome);
+        self.streams.push(Some(stream));
+        return outcome;
+    }
+
+    // Rewind Function:
+    // Parameters:
+    // - later: The source of the same game at a later point.
+    // Description: Queues the outcomes that were picked after this point, so they are picked again in the same order.
+    //   Each stream is given back its own outcomes, so the next card drawn is the same whatever is played first.
+    pub fn Rewind(&mut self, later: &Chance) {
+
+        let picked_after = later.streams.iter().copied().zip(later.outcomes.iter().copied()).skip(self.outcomes.len());
+        self.queued = picked_after.chain(later.queued.iter().copied()).collect();
+    }
+
+    // Scramble Function:
+    // Parameters:
+    // - seed: Number the new generator is made from. It should differ from the seed of the game.
+    // Description: Replaces the generator with one made from another seed and forgets any queued outcomes. Used on
+    //   copies of a game, so that the copy cannot be used to see which cards the real game will draw.
+    pub fn Scramble(&mut self, seed: u64) {
+
+        self.random = StdRng::seed_from_u64(seed);
+        self.queued.clear();
+    }
+
+    // GetSeed Function:
+    // Description: Returns the seed the generator was made from.
+    pub fn GetSeed(&self) -> u64 {
+
+        return self.seed;
+    }
+
+    // GetOutcomes Function:
+    // Description: Returns every outcome picked so far.
+    pub fn GetOutcomes(&self) -> &[usize] {
+
+        return &self.outcomes;
+    }
+}
@@ -0,0 +1,221 @@
+// Std modules.
+use std::cmp::Reverse;
+
+// My modules.
+use crate::game::{Game, Action};
+use crate::game_board::TileState;
+
+// Points that the other side must be able to score on a bond zone before a restrict or destroy is used.
+const RESTRICT_THREAT: u16 = 20;
+const DESTROY_THREAT: u16 = 40;
+
+/*
+Ability Algorithm:
+- Evolve: Used if evolving an atom in hand lets this turn's best play score more points.
+- Restrict: Used on the two bond zones where the other side could score the most points, if the best of them is
+  worth at least RESTRICT_THREAT points and the computer cannot score there itself.
+- Destroy: Used on the compound that owns the most valuable bond zone for the other side, if it is worth at
+  least DESTROY_THREAT points, no restrict is left, and the computer cannot score on any bond zone of the compound.
+- The other side's hand is unknown, so threats are measured against every card it could still be holding, played
+  with each of its oxidation states.
+*/
+
+// ChooseAbility Function:
+// Parameters:
+// - game: The game to choose an ability in. Must be in the ability phase.
+// Description: Chooses an ability for the side whose turn it is using the algorithm above.
+// Return: An ability action, or pass.
+pub fn ChooseAbility(game: &Game) -> Action {
+
+    let abilities = game.GetAbilities(game.GetCurrentSide());
+    let own_plays = Plays(game, Action::Pass);
+    let (own_points, own_tile) = BestPlay(&own_plays);
+
+    // Evolve the atom that improves the best play the most.
+    if abilities.evolve > 0 {
+
+        let mut best_points = own_points;
+        let mut best_action: Option<Action> = None;
+        for action in game.LegalActions() {
+
+            if let Action::Evolve { .. } = action {
+
+                let (points, _) = BestPlay(&Plays(game, action.clone()));
+                if points > best_points {
+
+                    best_points = points;
+                    best_action = Some(action);
+                }
+            }
+        }
+
+        if best_action != None {
+
+            return best_action.unwrap();
+        }
+    }
+
+    // Bond zones the other side could score on, best first. Zones the computer can score on itself are ignored.
+    let threats: Vec<(u16, (usize, usize))> = Threats(game).into_iter()
+        .filter(|threat| ZonePoints(&own_plays, threat.1) == 0)
+        .collect();
+
+    if threats.is_empty() {
+
+        return Action::Pass;
+    }
+
+    if (abilities.restrict > 0) && (threats[0].0 >= RESTRICT_THREAT) {
+
+        // The second tile is the next biggest threat, or any other tile that can be restricted.
+        let first = threats[0].1;
+        let board = game.GetBoard();
+        let mut second_options: Vec<(usize, usize)> = threats[1..].iter().map(|threat| threat.1).collect();
+        for i in 0..board.GetWidth() {
+
+            for j in 0..board.GetHeight() {
+
+                second_options.push((i, j));
+            }
+        }
+
+        for second in second_options {
+
+            // The computer's own best play tile is left open.
+            if Some(second) == own_tile {
+
+                continue;
+            }
+
+            let action = Action::Restrict { a: first, b: second };
+            if game.Validate(&action).is_ok() {
+
+                return action;
+            }
+        }
+    }
+
+    // Restricts are cheaper, so the compound is only destroyed once they have all been used.
+    // Compounds with a bond zone the computer can score on are left for the computer.
+    if (abilities.destroy > 0) && (abilities.restrict == 0) {
+
+        let board = game.GetBoard();
+        for threat in threats.iter().take_while(|threat| threat.0 >= DESTROY_THREAT) {
+
+            let owner = board.GetBondOwner(threat.1).unwrap();
+            let parent = board.CheckDestroy(owner);
+            let benefits = own_plays.iter().any(|play| {
+
+                (play.0 > 0) && (board.GetBondOwner(play.1).map(|zone_owner| board.CheckDestroy(zone_owner)) == Some(parent))
+            });
+
+            if !benefits {
+
+                return Action::Destroy { tile: owner };
+            }
+        }
+    }
+
+    return Action::Pass;
+}
+
+// Plays Function:
+// Parameters:
+// - game: The game to look at. Must be in the ability phase.
+// - ability: Ability action used before the play.
+// Description: Previews every play that could be made after using an ability.
+// Return: Points scored and the tile played on for each play. Empty if the ability cannot be used.
+fn Plays(game: &Game, ability: Action) -> Vec<(u16, (usize, usize))> {
+
+    let mut copy = game.clone();
+    if copy.Apply(ability).is_err() {
+
+        return Vec::new();
+    }
+
+    let mut plays: Vec<(u16, (usize, usize))> = Vec::new();
+    for action in copy.LegalActions() {
+
+        if let Action::Play { element, tile, oxidation, order } = action {
+
+            let points = copy.PreviewPlay(&element, tile, oxidation, order).unwrap().points.unwrap_or(0);
+            plays.push((points, tile));
+        }
+    }
+
+    return plays;
+}
+
+// BestPlay Function:
+// Parameters:
+// - plays: Plays found by Plays.
+// Description: Finds the play that scores the most points.
+// Return: Points scored and the tile played on. The tile is None if no play scores.
+fn BestPlay(plays: &[(u16, (usize, usize))]) -> (u16, Option<(usize, usize)>) {
+
+    let mut best: (u16, Option<(usize, usize)>) = (0, None);
+    for play in plays {
+
+        if play.0 > best.0 {
+
+            best = (play.0, Some(play.1));
+        }
+    }
+
+    return best;
+}
+
+// ZonePoints Function:
+// Parameters:
+// - plays: Plays found by Plays.
+// - tile: Coordinates of a bond zone.
+// Description: Returns the most points that can be scored by playing on a tile.
+fn ZonePoints(plays: &[(u16, (usize, usize))], tile: (usize, usize)) -> u16 {
+
+    return plays.iter().filter(|play| play.1 == tile).map(|play| play.0).max().unwrap_or(0);
+}
+
+// Threats Function:
+// Parameters:
+// - game: The game to look at.
+// Description: Scores every bond zone by the most points the other side could make there with a card it could
+//   still be holding. Transition metals are tried with every oxidation state.
+// Return: Vector of points and tiles, largest first. Zones worth no points are left out.
+fn Threats(game: &Game) -> Vec<(u16, (usize, usize))> {
+
+    let board = game.GetBoard();
+    let unseen = game.GetUnseenCards(game.GetCurrentSide());
+    let mut threats: Vec<(u16, (usize, usize))> = Vec::new();
+
+    for i in 0..board.GetWidth() {
+
+        for j in 0..board.GetHeight() {
+
+            if board.GetTileState((i, j)) != TileState::Bond {
+
+                continue;
+            }
+
+            let mut points: u16 = 0;
+            for card in unseen.iter() {
+
+                for bond_number in card.GetBondOptions() {
+
+                    match board.SimulateBond((i, j), &card.GetAtom(bond_number), None) {
+
+                        Ok(preview) => points = points.max(preview.points.unwrap_or(0)),
+                        Err(_) => (),
+                    }
+                }
+            }
+
+            if points > 0 {
+
+                threats.push((points, (i, j)));
+            }
+        }
+    }
+
+    threats.sort_by_key(|threat| Reverse(threat.0));
+    return threats;
+}
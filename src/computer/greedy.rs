@@ -0,0 +1,112 @@
+// My modules.
+use crate::game::{Game, Phase, Action};
+use super::Strategy;
+use super::abilities::ChooseAbility;
+
+/*
+First Zone Algorithm:
+- Description: Starting from (0, 0), find the first bond zone. Attempt to bond all cards in
+  hand to this zone. Only metal atoms are unable to bond within certain bond zones. Since there are only
+  four metals in the deck and five cards in hand, one atom is gaurenteed to bond.
+  If there are no bond zones, play on the first empty tile starting from (0, 0). If there are no empty tiles
+  either, the first legal action is taken.
+- Abilities are chosen with ChooseAbility.
+*/
+pub struct FirstZone;
+
+impl Strategy for FirstZone {
+
+    // ChooseAction Function:
+    // Parameters:
+    // - game: The game to choose an action in.
+    // Description: Chooses the next action for the side whose turn it is using the algorithm above.
+    // Return: An action which is valid for the current phase.
+    fn ChooseAction(&mut self, game: &Game) -> Action {
+
+        if game.GetPhase() == Phase::Ability {
+
+            return ChooseAbility(game);
+        }
+
+        let deck = game.GetDeck(game.GetCurrentSide());
+
+        let bond_zone_option = game.GetBoard().FindBondZone();
+        if bond_zone_option != None {
+
+            let bond_zone_coords = bond_zone_option.unwrap();
+
+            // Iterate over the cards in hand.
+            for i in 0..deck.GetHandSize() {
+
+                // Attempt to bond with card at given index.
+                let action = Action::Play { element: deck.GetFromIndex(i).name, tile: bond_zone_coords, oxidation: None, order: None };
+
+                // If there is no error with the bond operation, use this action.
+                if game.Validate(&action).is_ok() {
+
+                    return action;
+                }
+            }
+        }
+
+        // If there are no bond zones or no card could bond, play on an empty tile.
+        // Since the tile is empty, bonding is gaurenteed.
+        match game.GetBoard().FindEmptyZone() {
+
+            Some(empty_zone_coords) => return Action::Play { element: deck.GetFromIndex(0).name, tile: empty_zone_coords, oxidation: None, order: None },
+
+            // Every tile is taken, so take any play that is left.
+            None => return game.LegalActions().into_iter().next().unwrap_or(Action::Pass),
+        }
+    }
+}
+
+/*
+Best Score Algorithm:
+- Description: Previews every legal play and chooses the one that scores the most points. If no play completes a
+  compound, the play that forms the largest bond is chosen. Playing on an empty tile is the last choice, since it
+  starts a compound instead of working on one.
+- Abilities are chosen with ChooseAbility.
+*/
+pub struct BestScore;
+
+impl Strategy for BestScore {
+
+    // ChooseAction Function:
+    // Parameters:
+    // - game: The game to choose an action in.
+    // Description: Chooses the next action for the side whose turn it is using the algorithm above.
+    // Return: An action which is valid for the current phase.
+    fn ChooseAction(&mut self, game: &Game) -> Action {
+
+        if game.GetPhase() == Phase::Ability {
+
+            return ChooseAbility(game);
+        }
+
+        let legal = game.LegalActions();
+        let mut best_action = legal[0].clone();
+        let mut best_rank: (u16, u16) = (0, 0);
+
+        for action in legal {
+
+            match &action {
+
+                Action::Play { element, tile, oxidation, order } => {
+
+                    let preview = game.PreviewPlay(element, *tile, *oxidation, *order).unwrap();
+                    let rank = (preview.points.unwrap_or(0), preview.bonds_formed);
+
+                    if rank > best_rank {
+
+                        best_rank = rank;
+                        best_action = action.clone();
+                    }
+                },
+                _ => (),
+            }
+        }
+
+        return best_action;
+    }
+}
@@ -0,0 +1,308 @@
+// Rng crate.
+extern crate rand;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+// My modules.
+use crate::game::{Game, Side, Phase, Action};
+use crate::game_board::TileState;
+use super::{Strategy, CandidateActions};
+
+/*
+Monte Carlo Tree Search Algorithm:
+- Description: Information set MCTS. Every iteration starts by guessing at the hidden hand and deck of the other
+  side (Determinize), then walks a single shared tree using only the actions that are legal in that guess.
+  Unvisited actions are expanded one at a time and the rest of the game is played out with a quick random policy.
+  The result of the playout is recorded in every node on the path. After all iterations, the most visited action
+  is chosen.
+- Children are compared with UCB1, using the number of times a child was available instead of the number of
+  parent visits, since not every action exists in every guess.
+- A win is worth one point, a tie is worth half a point, and a loss is worth nothing.
+*/
+
+pub struct MonteCarlo {
+
+    // Number of playouts made before choosing an action.
+    pub iterations: u32,
+
+    // Weight of the exploration term within UCB1.
+    pub exploration: f64,
+
+    // Largest number of actions expanded at each node.
+    pub max_branching: usize
+}
+
+// One thousand playouts per action.
+impl Default for MonteCarlo {
+    fn default() -> Self {
+        MonteCarlo {
+
+            iterations: 1000,
+            exploration: 0.7,
+            max_branching: 12
+        }
+    }
+}
+
+// Node within the search tree. Nodes are stored in a vector and refer to each other by index.
+struct Node {
+
+    // Action that leads to this node and the side that used it. None for the root.
+    action: Option<Action>,
+    side: Side,
+
+    // Playout statistics from the point of view of side.
+    visits: u32,
+    wins: f64,
+    available: u32,
+
+    children: Vec<usize>
+}
+
+impl MonteCarlo {
+
+    // UpperBound Function:
+    // Parameters:
+    // - node: The child node to score.
+    // Description: UCB1 value of a node. Unvisited nodes are always tried first.
+    fn UpperBound(&self, node: &Node) -> f64 {
+
+        if node.visits == 0 {
+
+            return f64::MAX;
+        }
+
+        let visits = node.visits as f64;
+        let exploit = node.wins / visits;
+        let explore = self.exploration * ((node.available as f64).ln() / visits).sqrt();
+
+        return exploit + explore;
+    }
+
+    // Reward Function:
+    // Parameters:
+    // - game: A finished game.
+    // - side: The side to score the game for.
+    // Description: Returns one for a win, one half for a tie, and zero for a loss.
+    fn Reward(game: &Game, side: Side) -> f64 {
+
+        match game.GetWinner() {
+
+            Some(winner) => {
+
+                if winner == side {
+
+                    return 1.0;
+                }
+
+                return 0.0;
+            },
+            None => return 0.5,
+        }
+    }
+
+    // Playout Function:
+    // Parameters:
+    // - game: The game to finish.
+    // - random: Random number generator used to choose moves.
+    // Description: Plays the game to the end. Abilities are skipped and each atom is played on a random bond zone
+    //   if one accepts it. Otherwise it is played on a random empty tile.
+    fn Playout(game: &mut Game, random: &mut impl Rng) {
+
+        while !game.IsOver() {
+
+            if game.GetPhase() == Phase::Ability {
+
+                let _ = game.Apply(Action::Pass);
+                continue;
+            }
+
+            // Sort the open tiles into bond zones and empty zones.
+            let board = game.GetBoard();
+            let mut bond_zones: Vec<(usize, usize)> = Vec::new();
+            let mut empty_zones: Vec<(usize, usize)> = Vec::new();
+            for i in 0..board.GetWidth() {
+
+                for j in 0..board.GetHeight() {
+
+                    match board.GetTileState((i, j)) {
+
+                        TileState::Bond => bond_zones.push((i, j)),
+                        TileState::Empty => empty_zones.push((i, j)),
+                        _ => (),
+                    }
+                }
+            }
+
+            bond_zones.shuffle(random);
+            empty_zones.shuffle(random);
+
+            // Names of the cards in hand in a random order.
+            let deck = game.GetDeck(game.GetCurrentSide());
+            let mut names: Vec<String> = (0..deck.GetHandSize()).map(|i| deck.GetFromIndex(i).name).collect();
+            names.shuffle(random);
+
+            let mut chosen: Option<Action> = None;
+            'search: for tile in bond_zones.iter().chain(empty_zones.iter()) {
+
+                for name in names.iter() {
+
+                    let action = Action::Play { element: name.clone(), tile: *tile, oxidation: None, order: None };
+                    if game.Validate(&action).is_ok() {
+
+                        chosen = Some(action);
+                        break 'search;
+                    }
+                }
+            }
+
+            // If nothing can be played, fall back to any legal action so the game still ends.
+            match chosen {
+
+                Some(action) => { let _ = game.Apply(action); },
+                None => {
+
+                    let legal = game.LegalActions();
+                    if legal.is_empty() {
+
+                        return;
+                    }
+
+                    let _ = game.Apply(legal[0].clone());
+                },
+            }
+        }
+    }
+}
+
+impl Strategy for MonteCarlo {
+
+    // ChooseAction Function:
+    // Parameters:
+    // - game: The game to choose an action in.
+    // Description: Runs the configured number of iterations from the current position.
+    // Return: The most visited action.
+    fn ChooseAction(&mut self, game: &Game) -> Action {
+
+        let root_side = game.GetCurrentSide();
+        // Moves are chosen from a generator made from the seed of the game, so the same game gets the same choices.
+        let mut random = StdRng::seed_from_u64(game.ComputerSeed(0));
+
+        let root_actions = CandidateActions(game, self.max_branching);
+        if root_actions.len() == 1 {
+
+            return root_actions[0].clone();
+        }
+
+        let mut tree: Vec<Node> = vec![Node {
+
+            action: None,
+            side: root_side.Other(),
+            visits: 0,
+            wins: 0.0,
+            available: 0,
+            children: Vec::new()
+        }];
+
+        for iteration in 0..self.iterations {
+
+            // Guess at the hidden cards. Seed zero belongs to the generator above.
+            let mut sample = game.clone();
+            sample.Determinize(root_side, iteration as u64 + 1);
+
+            // Selection and expansion.
+            let mut path: Vec<usize> = vec![0];
+            let mut node_index = 0;
+            while !sample.IsOver() {
+
+                let legal = CandidateActions(&sample, self.max_branching);
+
+                // Actions which do not have a child yet.
+                let untried: Vec<&Action> = legal.iter()
+                    .filter(|action| !tree[node_index].children.iter().any(|child| tree[*child].action.as_ref() == Some(*action)))
+                    .collect();
+
+                if !untried.is_empty() {
+
+                    let action = (*untried.choose(&mut random).unwrap()).clone();
+                    let side = sample.GetCurrentSide();
+                    let _ = sample.Apply(action.clone());
+
+                    tree.push(Node {
+
+                        action: Some(action),
+                        side,
+                        visits: 0,
+                        wins: 0.0,
+                        available: 0,
+                        children: Vec::new()
+                    });
+
+                    let child_index = tree.len() - 1;
+                    tree[node_index].children.push(child_index);
+                    path.push(child_index);
+                    break;
+                }
+
+                // Every legal action has a child. Choose between the children that are available in this guess.
+                let mut best_child: Option<usize> = None;
+                let mut best_value = f64::MIN;
+                for child_index in tree[node_index].children.clone() {
+
+                    if !legal.contains(tree[child_index].action.as_ref().unwrap()) {
+
+                        continue;
+                    }
+
+                    let child = &mut tree[child_index];
+                    child.available += 1;
+
+                    let value = self.UpperBound(child);
+                    if value > best_value {
+
+                        best_value = value;
+                        best_child = Some(child_index);
+                    }
+                }
+
+                match best_child {
+
+                    Some(child_index) => {
+
+                        let _ = sample.Apply(tree[child_index].action.clone().unwrap());
+                        path.push(child_index);
+                        node_index = child_index;
+                    },
+                    None => break,
+                }
+            }
+
+            // Simulation.
+            MonteCarlo::Playout(&mut sample, &mut random);
+
+            // Backpropagation.
+            for index in path {
+
+                let node = &mut tree[index];
+                node.visits += 1;
+                node.wins += MonteCarlo::Reward(&sample, node.side);
+            }
+        }
+
+        // Choose the most visited action at the root.
+        let mut best_action = root_actions[0].clone();
+        let mut best_visits = 0;
+        for child_index in tree[0].children.iter() {
+
+            let child = &tree[*child_index];
+            if child.visits > best_visits {
+
+                best_visits = child.visits;
+                best_action = child.action.clone().unwrap();
+            }
+        }
+
+        return best_action;
+    }
+}
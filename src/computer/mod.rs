@@ -0,0 +1,165 @@
+// Std modules.
+use std::cmp::Reverse;
+
+// My modules.
+use crate::game::{Game, Action};
+use crate::game_board::TileState;
+
+// Rules the computer uses to decide when to use abilities.
+pub mod abilities;
+
+// Computer opponents which only look at the current turn.
+pub mod greedy;
+use greedy::{FirstZone, BestScore};
+
+// Search based computer opponent.
+pub mod search;
+use search::AlphaBeta;
+
+// Monte Carlo tree search computer opponent.
+pub mod mcts;
+use mcts::MonteCarlo;
+
+#[cfg(test)]
+mod tests;
+
+// Strategy Trait:
+// Description: A way of choosing actions for one side of a game. Every computer difficulty implements this trait.
+pub trait Strategy {
+
+    // ChooseAction Function:
+    // Parameters:
+    // - game: The game to choose an action in.
+    // Description: Chooses the next action for the side whose turn it is.
+    // Return: An action which is valid for the current phase.
+    fn ChooseAction(&mut self, game: &Game) -> Action;
+
+    // TakeTurn Function:
+    // Parameters:
+    // - game: The game to take a turn in.
+    // Description: Applies actions for the side whose turn it is until its turn is over.
+    fn TakeTurn(&mut self, game: &mut Game) {
+
+        let side = game.GetCurrentSide();
+        while !game.IsOver() && (game.GetCurrentSide() == side) {
+
+            let action = self.ChooseAction(game);
+            if game.Apply(action).is_ok() {
+
+                continue;
+            }
+
+            // Fall back to the first legal action so that a bad choice cannot stall the game.
+            match game.LegalActions().first() {
+
+                Some(fallback) => { let _ = game.Apply(fallback.clone()); },
+                None => return,
+            }
+        }
+    }
+}
+
+// Enum used to select how strong the computer plays.
+// - Easy: Plays into the first bond zone found. The original computer behavior.
+// - Medium: Plays the atom and tile which score the most points right now.
+// - Hard: Looks ahead with alpha-beta search.
+// - Expert: Monte Carlo tree search. The strongest computer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+
+    // FromName Function:
+    // Parameters:
+    // - name: Name of the difficulty typed by the user.
+    // Description: Converts a name into a difficulty. Not case sensitive.
+    // Return: None if the name is not a difficulty.
+    pub fn FromName(name: &str) -> Option<Difficulty> {
+
+        match name.to_lowercase().as_str() {
+
+            "easy" => return Some(Difficulty::Easy),
+            "medium" => return Some(Difficulty::Medium),
+            "hard" => return Some(Difficulty::Hard),
+            "expert" => return Some(Difficulty::Expert),
+            _ => return None,
+        }
+    }
+
+    // GetName Function:
+    // Description: Returns the name of the difficulty.
+    pub fn GetName(self) -> String {
+
+        match self {
+
+            Difficulty::Easy => return String::from("easy"),
+            Difficulty::Medium => return String::from("medium"),
+            Difficulty::Hard => return String::from("hard"),
+            Difficulty::Expert => return String::from("expert"),
+        }
+    }
+
+    // CreateStrategy Function:
+    // Description: Creates the computer player used by the difficulty.
+    pub fn CreateStrategy(self) -> Box<dyn Strategy> {
+
+        match self {
+
+            Difficulty::Easy => return Box::new(FirstZone),
+            Difficulty::Medium => return Box::new(BestScore),
+            Difficulty::Hard => return Box::new(AlphaBeta::default()),
+            Difficulty::Expert => return Box::new(MonteCarlo::default()),
+        }
+    }
+}
+
+// CandidateActions Function:
+// Parameters:
+// - game: The game to list actions for.
+// - limit: Largest number of actions to return.
+// Description: Lists the legal actions that are worth searching, best first. Plays are ordered by the points they
+//   score and the size of the bond they form. Restricts are only kept when both tiles are bond zones, since
+//   restricting empty space rarely matters. Pass is always kept.
+// Return: Vector of at most limit actions.
+pub fn CandidateActions(game: &Game, limit: usize) -> Vec<Action> {
+
+    let board = game.GetBoard();
+    let mut ranked: Vec<(i32, Action)> = Vec::new();
+
+    for action in game.LegalActions() {
+
+        let rank: i32;
+        match &action {
+
+            Action::Play { element, tile, oxidation, order } => {
+
+                let preview = game.PreviewPlay(element, *tile, *oxidation, *order).unwrap();
+                rank = (preview.points.unwrap_or(0) as i32 * 100) + (preview.bonds_formed as i32 * 10);
+            },
+            Action::Restrict { a, b } => {
+
+                if (board.GetTileState(*a) != TileState::Bond) || (board.GetTileState(*b) != TileState::Bond) {
+
+                    continue;
+                }
+
+                rank = 0;
+            },
+            Action::Pass => rank = i32::MAX,
+            _ => rank = 0,
+        }
+
+        ranked.push((rank, action));
+    }
+
+    // Stable sort keeps the board order between actions with the same rank.
+    ranked.sort_by_key(|pair| Reverse(pair.0));
+    ranked.truncate(limit);
+
+    return ranked.into_iter().map(|pair| pair.1).collect();
+}
@@ -0,0 +1,168 @@
+// My modules.
+use crate::game::{Game, Side, Action};
+use super::{Strategy, CandidateActions};
+
+/*
+Alpha-Beta Algorithm:
+- Description: The computer cannot see the other hand or the order of either deck. For each sample, the hidden
+  hand is replaced with a random hand dealt from the unseen cards (Determinize) and every candidate action is
+  searched with alpha-beta pruning. Random draws during the search are sampled as they happen, so each sample
+  also follows one possible order of draws. The action with the best total over all samples is chosen.
+- The node limit is split evenly between every sample and root action, so every total is made from searches of the
+  same size and the order of the candidates does not change the choice.
+- Each ply of the search is a single action. A full turn is two plies: the ability phase and the main phase.
+- Positions are scored as the searching side's score minus the other side's score.
+*/
+
+pub struct AlphaBeta {
+
+    // Number of plies searched below the root.
+    pub depth: u8,
+
+    // Number of positions expanded before the search stops, shared evenly by every sample and root action. A count is
+    // used instead of a time limit so that the same game always gets the same choice, however fast the machine is.
+    pub node_limit: u32,
+
+    // Number of guesses made at the hidden hand.
+    pub samples: u32,
+
+    // Largest number of actions expanded at each position.
+    pub max_branching: usize
+}
+
+// Searches two full turns ahead, expanding at most twenty thousand positions.
+impl Default for AlphaBeta {
+    fn default() -> Self {
+        AlphaBeta {
+
+            depth: 4,
+            node_limit: 20000,
+            samples: 8,
+            max_branching: 10
+        }
+    }
+}
+
+impl AlphaBeta {
+
+    // Search Function:
+    // Parameters:
+    // - game: Position to score.
+    // - depth: Remaining plies to search.
+    // - alpha and beta: Bounds used to prune positions that cannot change the result.
+    // - root_side: The side that the search is choosing an action for.
+    // - nodes: Number of positions that can still be expanded. Positions are no longer expanded once it reaches zero.
+    // Description: Alpha-beta search. The root side maximizes and the other side minimizes.
+    // Return: Value of the position for the root side.
+    fn Search(&self, game: &Game, depth: u8, mut alpha: i32, mut beta: i32, root_side: Side, nodes: &mut u32) -> i32 {
+
+        if (depth == 0) || game.IsOver() || (*nodes == 0) {
+
+            return AlphaBeta::Evaluate(game, root_side);
+        }
+
+        *nodes -= 1;
+
+        let maximizing = game.GetCurrentSide() == root_side;
+        let mut best_value: Option<i32> = None;
+
+        for action in CandidateActions(game, self.max_branching) {
+
+            let mut child = game.clone();
+            if child.Apply(action).is_err() {
+
+                continue;
+            }
+
+            let value = self.Search(&child, depth - 1, alpha, beta, root_side, nodes);
+
+            if maximizing {
+
+                best_value = Some(best_value.map_or(value, |best| best.max(value)));
+                alpha = alpha.max(value);
+            }
+            else {
+
+                best_value = Some(best_value.map_or(value, |best| best.min(value)));
+                beta = beta.min(value);
+            }
+
+            // The other side will never allow this position.
+            if alpha >= beta {
+
+                break;
+            }
+        }
+
+        return best_value.unwrap_or(AlphaBeta::Evaluate(game, root_side));
+    }
+
+    // Evaluate Function:
+    // Parameters:
+    // - game: Position to score.
+    // - root_side: The side that the score is for.
+    // Description: Returns the score of the root side minus the score of the other side.
+    fn Evaluate(game: &Game, root_side: Side) -> i32 {
+
+        return game.GetScore(root_side) as i32 - game.GetScore(root_side.Other()) as i32;
+    }
+}
+
+impl Strategy for AlphaBeta {
+
+    // ChooseAction Function:
+    // Parameters:
+    // - game: The game to choose an action in.
+    // Description: Searches the candidate actions for the side whose turn it is.
+    // Return: The action with the best total value over all samples. Actions that cannot be applied are never chosen
+    //   unless none of them can.
+    fn ChooseAction(&mut self, game: &Game) -> Action {
+
+        let root_side = game.GetCurrentSide();
+
+        let actions = CandidateActions(game, self.max_branching);
+        if actions.len() == 1 {
+
+            return actions[0].clone();
+        }
+
+        // Every search gets the same share of the node limit.
+        let searches = (self.samples.max(1) as usize * actions.len()) as u32;
+        let budget = (self.node_limit / searches).max(1);
+
+        // None once an action could not be applied.
+        let mut totals: Vec<Option<i32>> = vec![Some(0); actions.len()];
+        for sample_number in 0..self.samples {
+
+            // Guess at the hidden hand.
+            let mut sample = game.clone();
+            sample.Determinize(root_side, sample_number as u64);
+
+            for i in 0..actions.len() {
+
+                let mut child = sample.clone();
+                if child.Apply(actions[i].clone()).is_err() {
+
+                    totals[i] = None;
+                    continue;
+                }
+
+                let mut nodes = budget;
+                let value = self.Search(&child, self.depth.saturating_sub(1), i32::MIN, i32::MAX, root_side, &mut nodes);
+                totals[i] = totals[i].map(|total| total.saturating_add(value));
+            }
+        }
+
+        // The first action wins ties, which keeps the candidate ordering.
+        let mut best_index = 0;
+        for i in 1..actions.len() {
+
+            if totals[i] > totals[best_index] {
+
+                best_index = i;
+            }
+        }
+
+        return actions[best_index].clone();
+    }
+}
@@ -0,0 +1,232 @@
+// My modules.
+use super::Strategy;
+use super::greedy::{FirstZone, BestScore};
+use super::search::AlphaBeta;
+use super::mcts::MonteCarlo;
+use super::abilities::ChooseAbility;
+use crate::game::{Game, GameConfig, Side, Phase, Action};
+use crate::chance::Chance;
+use crate::save_file::SaveWriter;
+
+// PlayGame Function:
+// Parameters:
+// - seed: Seed of the game.
+// - player: Strategy used by the player.
+// - opponent: Strategy used by the opponent.
+// Description: Plays a whole game between two computers.
+// Return: The action log as it is written to a save file, followed by both scores.
+fn PlayGame(seed: u64, mut player: Box<dyn Strategy>, mut opponent: Box<dyn Strategy>) -> (String, u16, u16) {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(seed));
+    while !game.IsOver() {
+
+        match game.GetCurrentSide() {
+
+            Side::Player => player.TakeTurn(&mut game),
+            Side::Opponent => opponent.TakeTurn(&mut game),
+        }
+    }
+
+    let mut writer = SaveWriter::New("log");
+    game.GetLog().Save(&mut writer);
+    return (writer.Finish(), game.GetScore(Side::Player), game.GetScore(Side::Opponent));
+}
+
+// SmallSearch Function:
+// Description: A hard computer with a node budget small enough for a test.
+fn SmallSearch() -> Box<dyn Strategy> {
+
+    return Box::new(AlphaBeta { depth: 2, node_limit: 300, samples: 2, max_branching: 6 });
+}
+
+// SmallTree Function:
+// Description: An expert computer with few enough playouts for a test.
+fn SmallTree() -> Box<dyn Strategy> {
+
+    return Box::new(MonteCarlo { iterations: 60, exploration: 0.7, max_branching: 6 });
+}
+
+// OwnPlays Function:
+// Parameters:
+// - game: The game to look at. Must be in the ability phase.
+// - ability: Ability action used before the play.
+// Description: Returns the points and tile of every play the current side could make after using an ability.
+fn OwnPlays(game: &Game, ability: Action) -> Vec<(u16, (usize, usize))> {
+
+    let mut copy = game.clone();
+    copy.Apply(ability).unwrap();
+
+    let mut plays: Vec<(u16, (usize, usize))> = Vec::new();
+    for action in copy.LegalActions() {
+
+        if let Action::Play { element, tile, oxidation, order } = action {
+
+            plays.push((copy.PreviewPlay(&element, tile, oxidation, order).unwrap().points.unwrap_or(0), tile));
+        }
+    }
+
+    return plays;
+}
+
+// CheckAbility Function:
+// Parameters:
+// - game: The game the ability was chosen in.
+// - action: The ability chosen by ChooseAbility.
+// Description: Checks that the ability follows the ability algorithm.
+fn CheckAbility(game: &Game, action: &Action) {
+
+    game.Validate(action).unwrap();
+
+    let board = game.GetBoard();
+    let own_plays = OwnPlays(game, Action::Pass);
+    let own_best = own_plays.iter().map(|play| play.0).max().unwrap_or(0);
+
+    match action {
+
+        // Evolving must improve the best play.
+        Action::Evolve { .. } => {
+
+            let evolved_best = OwnPlays(game, action.clone()).iter().map(|play| play.0).max().unwrap_or(0);
+            assert!(evolved_best > own_best);
+        },
+
+        // Zones the computer can score on are not restricted first, and its own best play is left open.
+        Action::Restrict { a, b } => {
+
+            assert!(own_plays.iter().all(|play| (play.1 != *a) || (play.0 == 0)));
+            let own_tile = own_plays.iter().find(|play| (play.0 == own_best) && (own_best > 0)).map(|play| play.1);
+            assert_ne!(Some(*b), own_tile);
+        },
+
+        // Compounds are only destroyed once restricts are used up, and never one the computer can score on.
+        Action::Destroy { tile } => {
+
+            assert_eq!(game.GetAbilities(game.GetCurrentSide()).restrict, 0);
+
+            let parent = board.CheckDestroy(*tile);
+            for play in own_plays.iter().filter(|play| play.0 > 0) {
+
+                assert_ne!(board.GetBondOwner(play.1).map(|owner| board.CheckDestroy(owner)), Some(parent));
+            }
+        },
+        _ => (),
+    }
+}
+
+#[test]
+fn AbilitiesFollowTheAlgorithm() {
+
+    let mut used: Vec<Action> = Vec::new();
+    for seed in 10..15 {
+
+        let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(seed));
+        while !game.IsOver() {
+
+            let action: Action;
+            if game.GetPhase() == Phase::Ability {
+
+                action = ChooseAbility(&game);
+                CheckAbility(&game, &action);
+                if action != Action::Pass {
+
+                    used.push(action.clone());
+                }
+            }
+            else {
+
+                action = BestScore.ChooseAction(&game);
+            }
+
+            game.Apply(action).unwrap();
+        }
+    }
+
+    // Every kind of ability was used at least once.
+    assert!(used.iter().any(|action| matches!(action, Action::Evolve { .. })));
+    assert!(used.iter().any(|action| matches!(action, Action::Restrict { .. })));
+    assert!(used.iter().any(|action| matches!(action, Action::Destroy { .. })));
+}
+
+#[test]
+fn GreedyGamesRepeatWithTheSameSeed() {
+
+    let first = PlayGame(41, Box::new(BestScore), Box::new(FirstZone));
+    let second = PlayGame(41, Box::new(BestScore), Box::new(FirstZone));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn SearchGamesRepeatWithTheSameSeed() {
+
+    let first = PlayGame(42, SmallSearch(), SmallTree());
+    let second = PlayGame(42, SmallSearch(), SmallTree());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn DifferentSeedsGiveDifferentGames() {
+
+    let first = PlayGame(43, Box::new(BestScore), Box::new(FirstZone));
+    let second = PlayGame(44, Box::new(BestScore), Box::new(FirstZone));
+    assert_ne!(first.0, second.0);
+}
+
+#[test]
+fn ShallowSearchTakesTheBestPlay() {
+
+    // With one ply, every play is scored by the points it makes, so the search agrees with the best preview.
+    let mut search = AlphaBeta { depth: 1, node_limit: 50, samples: 2, max_branching: 200 };
+    let mut scored = 0;
+
+    for seed in 81..84 {
+
+        let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(seed));
+        while !game.IsOver() {
+
+            if game.GetPhase() == Phase::Ability {
+
+                game.Apply(Action::Pass).unwrap();
+                continue;
+            }
+
+            let best = game.LegalActions().into_iter().filter_map(|action| match action {
+
+                Action::Play { element, tile, oxidation, order } => game.PreviewPlay(&element, tile, oxidation, order).unwrap().points,
+                _ => None,
+            }).max().unwrap_or(0);
+
+            let action = search.ChooseAction(&game);
+            let outcome = game.Apply(action).unwrap();
+            assert_eq!(outcome.points.unwrap_or(0), best);
+            scored += (best > 0) as u32;
+        }
+    }
+
+    assert!(scored > 0);
+}
+
+#[test]
+fn FirstZoneFillsTheBoard() {
+
+    // A 3x3 board runs out of empty tiles long before the game ends.
+    let config = GameConfig { board_size: (3, 3), ..GameConfig::default() };
+    let mut full_boards = 0;
+
+    for seed in 0..10 {
+
+        let mut game = Game::NewWithChance("Ana", "Ben", config.clone(), Chance::Seeded(seed));
+        while !game.IsOver() {
+
+            let action = FirstZone.ChooseAction(&game);
+            if (game.GetPhase() == Phase::Main) && game.GetBoard().FindEmptyZone().is_none() {
+
+                full_boards += 1;
+            }
+
+            assert_eq!(game.Validate(&action), Ok(()), "seed {}", seed);
+            game.Apply(action).unwrap();
+        }
+    }
+
+    assert!(full_boards > 0);
+}
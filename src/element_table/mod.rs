@@ -0,0 +1,133 @@
+// Sub modules.
+mod transition_metals;
+mod polyatomic_ions;
+use transition_metals::TRANSITION_METALS;
+pub use polyatomic_ions::Ion;
+#[cfg(test)]
+mod tests;
+
+// Struct holding the data of one element from the periodic table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Element {
+
+    // Symbol shown on cards and tiles, and the full name of the element.
+    pub symbol: &'static str,
+    pub name: &'static str,
+
+    // Position within the periodic table.
+    pub atomic_number: u16,
+    pub group: u8,
+    pub period: u8,
+
+    pub is_metal: bool,
+
+    // Pauling electronegativity times one hundred, so that it can be compared without rounding. (Example: 220 is 2.20)
+    // None where no Pauling value is defined.
+    pub electronegativity: Option<u16>,
+
+    // Number of bonds a transition metal can form, one for each oxidation state. The first is used when no state is
+    // chosen. Empty for main group elements, which always form the number of bonds given by their group.
+    pub oxidation_states: &'static [u16]
+}
+
+// Main group elements of periods one to four. Metalloids are not metals.
+const ELEMENTS: [Element; 26] = [
+
+    // Period 1.
+    Element { symbol: "H", name: "Hydrogen", atomic_number: 1, group: 1, period: 1, is_metal: false, electronegativity: Some(220), oxidation_states: &[] },
+    Element { symbol: "He", name: "Helium", atomic_number: 2, group: 18, period: 1, is_metal: false, electronegativity: None, oxidation_states: &[] },
+
+    // Period 2.
+    Element { symbol: "Li", name: "Lithium", atomic_number: 3, group: 1, period: 2, is_metal: true, electronegativity: Some(98), oxidation_states: &[] },
+    Element { symbol: "Be", name: "Beryllium", atomic_number: 4, group: 2, period: 2, is_metal: true, electronegativity: Some(157), oxidation_states: &[] },
+    Element { symbol: "B", name: "Boron", atomic_number: 5, group: 13, period: 2, is_metal: false, electronegativity: Some(204), oxidation_states: &[] },
+    Element { symbol: "C", name: "Carbon", atomic_number: 6, group: 14, period: 2, is_metal: false, electronegativity: Some(255), oxidation_states: &[] },
+    Element { symbol: "N", name: "Nitrogen", atomic_number: 7, group: 15, period: 2, is_metal: false, electronegativity: Some(304), oxidation_states: &[] },
+    Element { symbol: "O", name: "Oxygen", atomic_number: 8, group: 16, period: 2, is_metal: false, electronegativity: Some(344), oxidation_states: &[] },
+    Element { symbol: "F", name: "Fluorine", atomic_number: 9, group: 17, period: 2, is_metal: false, electronegativity: Some(398), oxidation_states: &[] },
+    Element { symbol: "Ne", name: "Neon", atomic_number: 10, group: 18, period: 2, is_metal: false, electronegativity: None, oxidation_states: &[] },
+
+    // Period 3.
+    Element { symbol: "Na", name: "Sodium", atomic_number: 11, group: 1, period: 3, is_metal: true, electronegativity: Some(93), oxidation_states: &[] },
+    Element { symbol: "Mg", name: "Magnesium", atomic_number: 12, group: 2, period: 3, is_metal: true, electronegativity: Some(131), oxidation_states: &[] },
+    Element { symbol: "Al", name: "Aluminium", atomic_number: 13, group: 13, period: 3, is_metal: true, electronegativity: Some(161), oxidation_states: &[] },
+    Element { symbol: "Si", name: "Silicon", atomic_number: 14, group: 14, period: 3, is_metal: false, electronegativity: Some(190), oxidation_states: &[] },
+    Element { symbol: "P", name: "Phosphorus", atomic_number: 15, group: 15, period: 3, is_metal: false, electronegativity: Some(219), oxidation_states: &[] },
+    Element { symbol: "S", name: "Sulfur", atomic_number: 16, group: 16, period: 3, is_metal: false, electronegativity: Some(258), oxidation_states: &[] },
+    Element { symbol: "Cl", name: "Chlorine", atomic_number: 17, group: 17, period: 3, is_metal: false, electronegativity: Some(316), oxidation_states: &[] },
+    Element { symbol: "Ar", name: "Argon", atomic_number: 18, group: 18, period: 3, is_metal: false, electronegativity: None, oxidation_states: &[] },
+
+    // Period 4.
+    Element { symbol: "K", name: "Potassium", atomic_number: 19, group: 1, period: 4, is_metal: true, electronegativity: Some(82), oxidation_states: &[] },
+    Element { symbol: "Ca", name: "Calcium", atomic_number: 20, group: 2, period: 4, is_metal: true, electronegativity: Some(100), oxidation_states: &[] },
+    Element { symbol: "Ga", name: "Gallium", atomic_number: 31, group: 13, period: 4, is_metal: true, electronegativity: Some(181), oxidation_states: &[] },
+    Element { symbol: "Ge", name: "Germanium", atomic_number: 32, group: 14, period: 4, is_metal: false, electronegativity: Some(201), oxidation_states: &[] },
+    Element { symbol: "As", name: "Arsenic", atomic_number: 33, group: 15, period: 4, is_metal: false, electronegativity: Some(218), oxidation_states: &[] },
+    Element { symbol: "Se", name: "Selenium", atomic_number: 34, group: 16, period: 4, is_metal: false, electronegativity: Some(255), oxidation_states: &[] },
+    Element { symbol: "Br", name: "Bromine", atomic_number: 35, group: 17, period: 4, is_metal: false, electronegativity: Some(296), oxidation_states: &[] },
+    Element { symbol: "Kr", name: "Krypton", atomic_number: 36, group: 18, period: 4, is_metal: false, electronegativity: Some(300), oxidation_states: &[] },
+];
+
+impl Element {
+
+    // Find Function:
+    // Parameters:
+    // - symbol: Symbol of an element. (Example: Li)
+    // Description: Looks up an element within the table.
+    // Return: None if the element is not in the table.
+    pub fn Find(symbol: &str) -> Option<&'static Element> {
+
+        return ELEMENTS.iter().chain(TRANSITION_METALS.iter()).find(|element| element.symbol == symbol);
+    }
+
+    // IsTransitionMetal Function:
+    // Description: Returns true if the element is within groups three to twelve.
+    pub fn IsTransitionMetal(&self) -> bool {
+
+        return (3..=12).contains(&self.group);
+    }
+
+    // GetBondingCapacity Function:
+    // Description: Returns the number of bonds the atom can form, which is worked out from its group. Atoms bond until
+    //   they have eight valence electrons, so groups past fourteen count down again. Noble gases already have eight.
+    //   Transition metals use their first oxidation state.
+    // Return: Zero for noble gases.
+    pub fn GetBondingCapacity(&self) -> u16 {
+
+        if self.IsTransitionMetal() {
+
+            return self.oxidation_states[0];
+        }
+
+        match self.group {
+
+            1 | 17 => return 1,
+            2 | 16 => return 2,
+            13 | 15 => return 3,
+            14 => return 4,
+            _ => return 0,
+        }
+    }
+
+    // GetBondOptions Function:
+    // Description: Returns every number of bonds the atom can be played with.
+    // Return: The oxidation states of a transition metal. Only the bonding capacity for other elements.
+    pub fn GetBondOptions(&self) -> Vec<u16> {
+
+        if self.IsTransitionMetal() {
+
+            return self.oxidation_states.to_vec();
+        }
+
+        return vec![self.GetBondingCapacity()];
+    }
+
+    // GetEvolution Function:
+    // Description: Returns the element an atom becomes when it is evolved. This is the element below it within the
+    //   same group, so atoms can keep evolving until the bottom of the table. (Example: C to Si to Ge)
+    // Return: None if there is no element below it.
+    pub fn GetEvolution(&self) -> Option<&'static Element> {
+
+        return ELEMENTS.iter().find(|element| (element.group == self.group) && (element.period == self.period + 1));
+    }
+}
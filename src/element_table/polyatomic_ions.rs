@@ -0,0 +1,79 @@
+// My modules.
+use super::Element;
+
+// Struct holding the data of a polyatomic ion. An ion is played as one card and takes up one tile.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Ion {
+
+    // Formula typed by the user and the full name of the ion.
+    pub formula: &'static str,
+    pub name: &'static str,
+
+    // Symbol of each atom within the ion and how many of it there are.
+    pub atoms: &'static [(&'static str, u16)],
+
+    // Net charge of the ion. Negative for anions and positive for cations.
+    pub charge: i8
+}
+
+// Common polyatomic ions. Tiles and cards show the first and last characters of the formula.
+const IONS: [Ion; 6] = [
+
+    Ion { formula: "OH", name: "Hydroxide", atoms: &[("O", 1), ("H", 1)], charge: -1 },
+    Ion { formula: "NO3", name: "Nitrate", atoms: &[("N", 1), ("O", 3)], charge: -1 },
+    Ion { formula: "CO3", name: "Carbonate", atoms: &[("C", 1), ("O", 3)], charge: -2 },
+    Ion { formula: "SO4", name: "Sulfate", atoms: &[("S", 1), ("O", 4)], charge: -2 },
+    Ion { formula: "PO4", name: "Phosphate", atoms: &[("P", 1), ("O", 4)], charge: -3 },
+    Ion { formula: "NH4", name: "Ammonium", atoms: &[("N", 1), ("H", 4)], charge: 1 },
+];
+
+impl Ion {
+
+    // Find Function:
+    // Parameters:
+    // - formula: Formula of an ion. (Example: SO4)
+    // Description: Looks up an ion within the table.
+    // Return: None if the ion is not in the table.
+    pub fn Find(formula: &str) -> Option<&'static Ion> {
+
+        return IONS.iter().find(|ion| ion.formula == formula);
+    }
+
+    // GetSymbol Function:
+    // Description: Returns the symbol shown on cards and tiles, which is the first and last characters of the formula.
+    //   The first two letters could be mistaken for an element, such as CO3 for cobalt. (Example: CO3 is shown as C3)
+    pub fn GetSymbol(&self) -> String {
+
+        let first = self.formula.chars().next().unwrap();
+        let last = self.formula.chars().last().unwrap();
+        return format!("{}{}", first, last);
+    }
+
+    // GetBondingCapacity Function:
+    // Description: Returns the number of bonds the ion can form, which is the size of its charge.
+    pub fn GetBondingCapacity(&self) -> u16 {
+
+        return self.charge.unsigned_abs() as u16;
+    }
+
+    // IsCation Function:
+    // Description: Returns true if the ion has a positive charge. Cations bond the same way metals do.
+    pub fn IsCation(&self) -> bool {
+
+        return self.charge > 0;
+    }
+
+    // GetAtomicSum Function:
+    // Description: Adds up the atomic numbers of every atom within the ion. (Example: SO4 is 16 + 4 * 8 = 48)
+    pub fn GetAtomicSum(&self) -> u16 {
+
+        let mut sum: u16 = 0;
+        for (symbol, count) in self.atoms.iter() {
+
+            // Every atom of the ion table is within the element table.
+            sum += Element::Find(symbol).unwrap().atomic_number * count;
+        }
+
+        return sum;
+    }
+}
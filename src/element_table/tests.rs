@@ -0,0 +1,84 @@
+// My modules.
+use super::{Element, Ion, ELEMENTS, TRANSITION_METALS};
+use crate::card_deck::Card;
+
+// Formula of every ion within the ion table.
+const FORMULAS: [&str; 6] = ["OH", "NO3", "CO3", "SO4", "PO4", "NH4"];
+
+#[test]
+fn IonSymbolsDoNotMatchElements() {
+
+    for formula in FORMULAS {
+
+        let symbol = Ion::Find(formula).unwrap().GetSymbol();
+        for element in ELEMENTS.iter().chain(TRANSITION_METALS.iter()) {
+
+            assert!(!symbol.eq_ignore_ascii_case(element.symbol), "{} is shown as {}", formula, element.symbol);
+        }
+    }
+
+    assert_eq!(Ion::Find("CO3").unwrap().GetSymbol(), "C3");
+}
+
+#[test]
+fn IonsBondWithTheirCharge() {
+
+    let sulfate = Ion::Find("SO4").unwrap();
+    assert_eq!(sulfate.GetBondingCapacity(), 2);
+    assert_eq!(sulfate.GetAtomicSum(), 48);
+    assert!(!sulfate.IsCation());
+
+    let ammonium = Ion::Find("NH4").unwrap();
+    assert_eq!(ammonium.GetBondingCapacity(), 1);
+    assert!(ammonium.IsCation());
+}
+
+#[test]
+fn TransitionMetalsListTheirOxidationStates() {
+
+    // The first oxidation state is the one used by default.
+    let iron = Element::Find("Fe").unwrap();
+    assert_eq!(iron.GetBondOptions(), vec![2, 3]);
+    assert_eq!(iron.GetBondingCapacity(), 2);
+
+    // Main group elements only have their group's number of bonds.
+    assert_eq!(Element::Find("C").unwrap().GetBondOptions(), vec![4]);
+
+    // An atom can only bond in four directions.
+    for metal in TRANSITION_METALS.iter() {
+
+        assert!(metal.oxidation_states.iter().all(|state| (1..=4).contains(state)), "{}", metal.symbol);
+    }
+}
+
+#[test]
+fn EvolutionFollowsTheGroupDown() {
+
+    // Carbon evolves twice before reaching the bottom of the table.
+    let silicon = Element::Find("C").unwrap().GetEvolution().unwrap();
+    assert_eq!(silicon.symbol, "Si");
+    let germanium = silicon.GetEvolution().unwrap();
+    assert_eq!(germanium.symbol, "Ge");
+    assert_eq!(germanium.GetEvolution(), None);
+
+    // Transition metals are not stacked within the main group table, so they do not evolve.
+    assert_eq!(Element::Find("Fe").unwrap().GetEvolution(), None);
+}
+
+#[test]
+fn BondingCapacityComesFromTheGroup() {
+
+    // Groups 1, 2, and 13 to 17 bond 1, 2, 3, 4, 3, 2, and 1 times. Noble gases do not bond.
+    let periods = [["Li", "Be", "B", "C", "N", "O", "F", "Ne"], ["Na", "Mg", "Al", "Si", "P", "S", "Cl", "Ar"]];
+    for period in periods {
+
+        let capacities: Vec<u16> = period.iter().map(|symbol| Element::Find(symbol).unwrap().GetBondingCapacity()).collect();
+        assert_eq!(capacities, vec![1, 2, 3, 4, 3, 2, 1, 0]);
+
+        // Cards take their number of bonds from the table.
+        for (symbol, capacity) in period.iter().zip(capacities) {
+
+            assert_eq!(Card::FromName(symbol).unwrap().number_of_bonds, capacity);
+        }
+    }
+}
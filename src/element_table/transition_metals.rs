@@ -0,0 +1,23 @@
+// My modules.
+use super::Element;
+
+/*
+Transition Metals:
+- Description: The transition metals of period four, from Sc to Zn. They are only used when the transition_metals
+  setting is on. Unlike main group elements, the number of bonds a transition metal forms is not set by its group.
+  Each one lists the oxidation states it is played with, and the player chooses one when playing it.
+- Oxidation states above four are left out, since an atom can only bond in four directions.
+*/
+pub const TRANSITION_METALS: [Element; 10] = [
+
+    Element { symbol: "Sc", name: "Scandium", atomic_number: 21, group: 3, period: 4, is_metal: true, electronegativity: Some(136), oxidation_states: &[3] },
+    Element { symbol: "Ti", name: "Titanium", atomic_number: 22, group: 4, period: 4, is_metal: true, electronegativity: Some(154), oxidation_states: &[4, 2, 3] },
+    Element { symbol: "V", name: "Vanadium", atomic_number: 23, group: 5, period: 4, is_metal: true, electronegativity: Some(163), oxidation_states: &[3, 2, 4] },
+    Element { symbol: "Cr", name: "Chromium", atomic_number: 24, group: 6, period: 4, is_metal: true, electronegativity: Some(166), oxidation_states: &[3, 2] },
+    Element { symbol: "Mn", name: "Manganese", atomic_number: 25, group: 7, period: 4, is_metal: true, electronegativity: Some(155), oxidation_states: &[2, 3, 4] },
+    Element { symbol: "Fe", name: "Iron", atomic_number: 26, group: 8, period: 4, is_metal: true, electronegativity: Some(183), oxidation_states: &[2, 3] },
+    Element { symbol: "Co", name: "Cobalt", atomic_number: 27, group: 9, period: 4, is_metal: true, electronegativity: Some(188), oxidation_states: &[2, 3] },
+    Element { symbol: "Ni", name: "Nickel", atomic_number: 28, group: 10, period: 4, is_metal: true, electronegativity: Some(191), oxidation_states: &[2] },
+    Element { symbol: "Cu", name: "Copper", atomic_number: 29, group: 11, period: 4, is_metal: true, electronegativity: Some(190), oxidation_states: &[2, 1] },
+    Element { symbol: "Zn", name: "Zinc", atomic_number: 30, group: 12, period: 4, is_metal: true, electronegativity: Some(165), oxidation_states: &[2] },
+];
@@ -0,0 +1,112 @@
+// Std modules.
+use std::fmt;
+
+// My modules.
+use crate::game_board::BoardError;
+use crate::save_file::SaveField;
+use super::Side;
+
+// Enum representing every move that can be made during a turn.
+// - Play: Main phase. Plays the atom named element from hand onto a tile. Transition metals can be given an oxidation
+//   state, and use their first one when none is given. A bond order can be given to form a smaller bond than the
+//   largest possible one.
+// - Destroy, Restrict, Evolve, and Pass: Ability phase.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Action {
+    Play { element: String, tile: (usize, usize), oxidation: Option<u16>, order: Option<u16> },
+    Destroy { tile: (usize, usize) },
+    Restrict { a: (usize, usize), b: (usize, usize) },
+    Evolve { element: String },
+    Pass,
+}
+
+// Actions are saved as their name followed by their parameters, separated by spaces. (Example: Play Li 0,3)
+// The oxidation state of a play is only written when one was given. (Example: Play Fe 0,3 3)
+// The bond order of a play follows the oxidation state, which is written as - when none was given. (Example: Play O 0,3 - 1)
+impl SaveField for Action {
+
+    fn ToField(&self) -> String {
+
+        match self {
+
+            Action::Play { element, tile, oxidation, order } => match (oxidation, order) {
+
+                (_, Some(order)) => return format!("Play {} {} {} {}", element, tile.ToField(), oxidation.ToField(), order),
+                (Some(state), None) => return format!("Play {} {} {}", element, tile.ToField(), state),
+                (None, None) => return format!("Play {} {}", element, tile.ToField()),
+            },
+            Action::Destroy { tile } => return format!("Destroy {}", tile.ToField()),
+            Action::Restrict { a, b } => return format!("Restrict {} {}", a.ToField(), b.ToField()),
+            Action::Evolve { element } => return format!("Evolve {}", element),
+            Action::Pass => return String::from("Pass"),
+        }
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        let parts: Vec<&str> = field.split(' ').collect();
+        match parts.as_slice() {
+
+            ["Play", element, tile] => return Some(Action::Play { element: String::from(*element), tile: SaveField::FromField(tile)?, oxidation: None, order: None }),
+            ["Play", element, tile, state] => return Some(Action::Play { element: String::from(*element), tile: SaveField::FromField(tile)?, oxidation: Some(SaveField::FromField(state)?), order: None }),
+            ["Play", element, tile, state, order] => return Some(Action::Play { element: String::from(*element), tile: SaveField::FromField(tile)?, oxidation: SaveField::FromField(state)?, order: Some(SaveField::FromField(order)?) }),
+            ["Destroy", tile] => return Some(Action::Destroy { tile: SaveField::FromField(tile)? }),
+            ["Restrict", a, b] => return Some(Action::Restrict { a: SaveField::FromField(a)?, b: SaveField::FromField(b)? }),
+            ["Evolve", element] => return Some(Action::Evolve { element: String::from(*element) }),
+            ["Pass"] => return Some(Action::Pass),
+            _ => return None,
+        }
+    }
+}
+
+// Enum describing why an action could not be used.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ActionError {
+    GameOver,
+    WrongPhase,
+    NoAbilityRemaining,
+    CardNotInHand,
+    CannotEvolve,
+    InvalidOxidation,
+    Board(BoardError),
+}
+
+// The error messages shown within the action log.
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        match self {
+
+            ActionError::GameOver => return write!(f, "Error: The game is over."),
+            ActionError::WrongPhase => return write!(f, "Error: Command cannot be used in this phase."),
+            ActionError::NoAbilityRemaining => return write!(f, "Error: No uses of that ability remain."),
+            ActionError::CardNotInHand => return write!(f, "Error: Atom is not in hand."),
+            ActionError::CannotEvolve => return write!(f, "Error: Atom cannot evolve any further."),
+            ActionError::InvalidOxidation => return write!(f, "Error: Atom cannot be played with that oxidation state."),
+            ActionError::Board(error) => return write!(f, "{}", error),
+        }
+    }
+}
+
+// Board errors are passed through to the caller of Apply.
+impl From<BoardError> for ActionError {
+    fn from(error: BoardError) -> Self {
+
+        return ActionError::Board(error);
+    }
+}
+
+// Struct describing the result of an action that was applied.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Outcome {
+
+    // Side that used the action.
+    pub side: Side,
+
+    // Score of the compound completed by a play. None if no compound was completed.
+    pub points: Option<u16>,
+
+    // Flags for the turn ending and the game ending as a result of the action.
+    pub turn_ended: bool,
+    pub game_over: bool
+}
@@ -0,0 +1,304 @@
+// Std modules.
+use std::fs;
+
+// My modules.
+use crate::game_board::{GameBoard, ScoringMode, DEFAULT_BOARD_SIZE, MIN_BOARD_SIZE, MAX_BOARD_SIZE};
+use crate::card_deck::DeckList;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
+// Largest number of turns in a game. Each side draws from its own deck, so very long games run out of cards.
+const MAX_TURNS: u8 = 40;
+
+// Largest number of cards in a hand. Larger hands do not fit on the screen.
+const MAX_HAND: u8 = 10;
+
+// Largest number of cards drawn at the start of each turn.
+const MAX_DRAW: u8 = 5;
+
+/*
+Config File Format:
+- Description: A config file is plain text with one setting per line. Each line holds the name of a setting followed
+  by its value. Blank lines and lines starting with # are skipped. Settings that are not given keep their standard
+  value.
+- Settings:
+  preset: Replaces every setting with a preset. Should come before the other settings. (Example: preset lunch)
+  turns: Total number of turns for both sides. Must be even, so that both sides get the same number of turns.
+  (Example: turns 16)
+  opening_hand: Cards drawn into each hand before the first turn. No more than max_hand are drawn.
+  draw_per_turn: Cards drawn at the start of each turn.
+  max_hand: Largest number of cards a hand can hold. Cards are not drawn into a full hand.
+  board: Width and height of the board. (Example: board 8x8)
+  scoring: How completed compounds are scored, classic or polarity. (Example: scoring polarity)
+  transition_metals: Allows transition metals within deck lists when on. Should come before the deck lists.
+  (Example: transition_metals on)
+  player_deck and opponent_deck: Deck list file used by each side. (Example: player_deck decks/carbon.txt)
+*/
+
+// Struct holding the rules that can change between games.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GameConfig {
+
+    // Total number of turns. Each side gets half, so the number is always even.
+    pub turns: u8,
+
+    // Cards drawn before the first turn, cards drawn at the start of each turn, and the most a hand can hold.
+    pub opening_hand: u8,
+    pub draw_per_turn: u8,
+    pub max_hand: u8,
+
+    // Width and height of the board.
+    pub board_size: (usize, usize),
+
+    // The way completed compounds are scored.
+    pub scoring: ScoringMode,
+
+    // True if deck lists can hold transition metals, which are played with a chosen oxidation state.
+    pub transition_metals: bool,
+
+    // The cards each side's deck is made from.
+    pub player_deck: DeckList,
+    pub opponent_deck: DeckList
+}
+
+// default Function:
+// - Description: The standard game. Sixteen turns with a five card hand on a 6x6 board, using the standard deck and
+//   no transition metals.
+impl Default for GameConfig {
+    fn default() -> Self {
+
+        return GameConfig {
+
+            turns: 16,
+            opening_hand: 4,
+            draw_per_turn: 1,
+            max_hand: 5,
+            board_size: DEFAULT_BOARD_SIZE,
+            scoring: ScoringMode::Classic,
+            transition_metals: false,
+            player_deck: DeckList::default(),
+            opponent_deck: DeckList::default()
+        };
+    }
+}
+
+impl GameConfig {
+
+    // FromPreset Function:
+    // Parameters:
+    // - name: Name of the preset typed by the user.
+    // Description: Returns the config of a preset.
+    // - standard: The default game.
+    // - lunch: A short game on a small board.
+    // - tournament: A long game on a large board with a bigger hand. Uses every card of the standard deck.
+    // Return: None if the name is not recognised.
+    pub fn FromPreset(name: &str) -> Option<GameConfig> {
+
+        match name {
+
+            "standard" => return Some(GameConfig::default()),
+            "lunch" => return Some(GameConfig { turns: 10, opening_hand: 3, draw_per_turn: 1, max_hand: 4, board_size: (5, 5), ..Default::default() }),
+            "tournament" => return Some(GameConfig { turns: 22, opening_hand: 5, draw_per_turn: 1, max_hand: 6, board_size: (8, 8), ..Default::default() }),
+            _ => return None,
+        }
+    }
+
+    // Set Function:
+    // Parameters:
+    // - key: Name of the setting.
+    // - value: The value typed by the user.
+    // Description: Changes one setting as described within the config file format.
+    // Return: Error message if the setting does not exist or the value is not allowed.
+    pub fn Set(&mut self, key: &str, value: &str) -> Result<(), String> {
+
+        let invalid = format!("\"{}\" is not a valid value for {}.", value, key);
+
+        match key {
+
+            "preset" => match GameConfig::FromPreset(value) {
+
+                Some(config) => *self = config,
+                None => return Err(String::from("Presets are standard, lunch, and tournament.")),
+            },
+            "turns" => self.turns = u8::FromField(value).ok_or(invalid)?,
+            "opening_hand" => self.opening_hand = u8::FromField(value).ok_or(invalid)?,
+            "draw_per_turn" => self.draw_per_turn = u8::FromField(value).ok_or(invalid)?,
+            "max_hand" => self.max_hand = u8::FromField(value).ok_or(invalid)?,
+            "board" => self.board_size = GameConfig::ParseBoardSize(value).ok_or(invalid)?,
+            "scoring" => self.scoring = ScoringMode::FromName(value).ok_or(String::from("scoring must be classic or polarity."))?,
+            "transition_metals" => match value {
+
+                "on" => self.transition_metals = true,
+                "off" => self.transition_metals = false,
+                _ => return Err(String::from("transition_metals must be on or off.")),
+            },
+            "player_deck" => self.player_deck = GameConfig::LoadDeckList(value)?,
+            "opponent_deck" => self.opponent_deck = GameConfig::LoadDeckList(value)?,
+            _ => return Err(format!("Unknown setting \"{}\".", key)),
+        }
+
+        return self.Check();
+    }
+
+    // Check Function:
+    // Description: Checks that every setting is within its limits.
+    // Return: Error message describing the first setting that is not.
+    pub fn Check(&self) -> Result<(), String> {
+
+        if !(2..=MAX_TURNS).contains(&self.turns) {
+
+            return Err(format!("turns must be from 2 to {}.", MAX_TURNS));
+        }
+
+        if !self.turns.is_multiple_of(2) {
+
+            return Err(String::from("turns must be even, so that both sides get the same number of turns."));
+        }
+
+        if !(1..=MAX_HAND).contains(&self.max_hand) {
+
+            return Err(format!("max_hand must be from 1 to {}.", MAX_HAND));
+        }
+
+        if self.opening_hand > MAX_HAND {
+
+            return Err(format!("opening_hand must be from 0 to {}.", MAX_HAND));
+        }
+
+        if !(1..=MAX_DRAW).contains(&self.draw_per_turn) {
+
+            return Err(format!("draw_per_turn must be from 1 to {}.", MAX_DRAW));
+        }
+
+        if !GameBoard::IsValidSize(self.board_size.0, self.board_size.1) {
+
+            return Err(format!("board must be from {0}x{0} to {1}x{1}.", MIN_BOARD_SIZE, MAX_BOARD_SIZE));
+        }
+
+        if !self.transition_metals && (self.player_deck.HasTransitionMetals() || self.opponent_deck.HasTransitionMetals()) {
+
+            return Err(String::from("Deck lists can only hold transition metals when transition_metals is on."));
+        }
+
+        return Ok(());
+    }
+
+    // ParseBoardSize Function:
+    // Parameters:
+    // - size: Width and height separated by an x. (Example: 8x8)
+    // Description: Converts a board size typed by the user.
+    // Return: Width and height. None if the size is not written correctly or is not allowed.
+    pub fn ParseBoardSize(size: &str) -> Option<(usize, usize)> {
+
+        let (width, height) = size.split_once('x')?;
+        let size: (usize, usize) = (width.parse().ok()?, height.parse().ok()?);
+
+        if !GameBoard::IsValidSize(size.0, size.1) {
+
+            return None;
+        }
+
+        return Some(size);
+    }
+
+    // LoadDeckList Function:
+    // Parameters:
+    // - path: The deck list file to read.
+    // Description: Reads a deck list named within a config file.
+    // Return: The deck list. Error message which names the deck list file.
+    fn LoadDeckList(path: &str) -> Result<DeckList, String> {
+
+        match DeckList::LoadFile(path) {
+
+            Ok(list) => return Ok(list),
+            Err(SaveError::Io(message)) => return Err(message),
+            Err(SaveError::Format { line, message }) => return Err(format!("Line {} of \"{}\": {}", line, path, message)),
+        }
+    }
+
+    // LoadFile Function:
+    // Parameters:
+    // - path: The config file to read.
+    // Description: Reads a config file written in the format above.
+    // Return: The config. Error pointing at the first line that could not be used.
+    pub fn LoadFile(path: &str) -> Result<GameConfig, SaveError> {
+
+        let text = match fs::read_to_string(path) {
+
+            Ok(text) => text,
+            Err(error) => return Err(SaveError::Io(format!("Could not read \"{}\". {}", path, error))),
+        };
+
+        let mut config = GameConfig::default();
+        for (index, line) in text.lines().enumerate() {
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 2 {
+
+                return Err(SaveError::Format { line: index + 1, message: String::from("Expected a setting followed by one value.") });
+            }
+
+            match config.Set(fields[0], fields[1]) {
+
+                Ok(_) => (),
+                Err(message) => return Err(SaveError::Format { line: index + 1, message }),
+            }
+        }
+
+        return Ok(config);
+    }
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes every setting as one record followed by both deck lists.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("config", &[
+            self.turns.ToField(),
+            self.opening_hand.ToField(),
+            self.draw_per_turn.ToField(),
+            self.max_hand.ToField(),
+            self.board_size.0.ToField(),
+            self.board_size.1.ToField(),
+            self.scoring.ToField(),
+            self.transition_metals.ToField()
+        ]);
+
+        self.player_deck.Save(writer);
+        self.opponent_deck.Save(writer);
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a config written by Save.
+    // Return: The config. Error if a setting is not allowed.
+    pub fn Load(reader: &mut SaveReader) -> Result<GameConfig, SaveError> {
+
+        let record = reader.Read("config")?;
+        let config = GameConfig {
+
+            turns: record.Get(0)?,
+            opening_hand: record.Get(1)?,
+            draw_per_turn: record.Get(2)?,
+            max_hand: record.Get(3)?,
+            board_size: (record.Get(4)?, record.Get(5)?),
+            scoring: record.Get(6)?,
+            transition_metals: record.Get(7)?,
+            player_deck: DeckList::Load(reader)?,
+            opponent_deck: DeckList::Load(reader)?
+        };
+
+        match config.Check() {
+
+            Ok(_) => return Ok(config),
+            Err(message) => return Err(record.Error(&message)),
+        }
+    }
+}
@@ -0,0 +1,1017 @@
+// Std modules.
+use std::fs;
+
+// My modules.
+use crate::game_board::{GameBoard, BondPreview};
+use crate::card_deck::{CardDeck, Card, DeckList};
+use crate::action_log::ActionLog;
+use crate::chance::{Chance, Stream};
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+mod config;
+pub use config::GameConfig;
+mod action;
+pub use action::{Action, ActionError, Outcome};
+mod replay;
+pub use replay::{Replay, ReplayStep};
+mod undo;
+pub use undo::{UndoHistory, UndoRule, UndoError};
+#[cfg(test)]
+mod tests;
+
+// First line of every save file. Changed if the format changes.
+const SAVE_HEADER: &str = "fcgreen6 save 10";
+
+// Number mixed into the seeds made for the computer, so that they differ from the seed of the real draws.
+const COMPUTER_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+// Enum used to identify the two sides of a match.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Player,
+    Opponent,
+}
+
+impl Side {
+
+    // Other Function:
+    // Description: Returns the side that is not self.
+    pub fn Other(self) -> Side {
+
+        match self {
+
+            Side::Player => return Side::Opponent,
+            Side::Opponent => return Side::Player,
+        }
+    }
+
+    // GetName Function:
+    // Description: Returns the default name of the side. Used when a game is created with Default.
+    pub fn GetName(self) -> String {
+
+        match self {
+
+            Side::Player => return String::from("Player"),
+            Side::Opponent => return String::from("Computer"),
+        }
+    }
+}
+
+// Sides are saved by name.
+impl SaveField for Side {
+
+    fn ToField(&self) -> String {
+
+        return format!("{:?}", self);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        match field {
+
+            "Player" => return Some(Side::Player),
+            "Opponent" => return Some(Side::Opponent),
+            _ => return None,
+        }
+    }
+}
+
+// Enum used to check which part of a turn the game is in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Ability,
+    Main,
+    GameOver,
+}
+
+// Phases are saved by name.
+impl SaveField for Phase {
+
+    fn ToField(&self) -> String {
+
+        return format!("{:?}", self);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        match field {
+
+            "Ability" => return Some(Phase::Ability),
+            "Main" => return Some(Phase::Main),
+            "GameOver" => return Some(Phase::GameOver),
+            _ => return None,
+        }
+    }
+}
+
+// Remaining uses of each ability for one side.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Abilities {
+
+    pub evolve: u8,
+    pub destroy: u8,
+    pub restrict: u8
+}
+
+// Struct holding everything that belongs to one side of the match.
+#[derive(Clone)]
+struct SideState {
+
+    // Name used for the side within the action log and on screen.
+    name: String,
+
+    deck: CardDeck,
+    score: u16,
+    abilities: Abilities,
+
+    // Deck list names of the atoms this side has played. Both sides can see these.
+    played: Vec<String>
+}
+
+impl SideState {
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the name, score, ability counters, played atoms, and cards of the side.
+    fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("side", &[
+            self.name.ToField(),
+            self.score.ToField(),
+            self.abilities.evolve.ToField(),
+            self.abilities.destroy.ToField(),
+            self.abilities.restrict.ToField()
+        ]);
+        writer.Write("played", &self.played);
+
+        self.deck.Save(writer);
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a side written by Save.
+    fn Load(reader: &mut SaveReader) -> Result<SideState, SaveError> {
+
+        let record = reader.Read("side")?;
+        let played: Vec<String> = reader.Read("played")?.GetAll(0)?;
+
+        return Ok(SideState {
+
+            name: record.Get(0)?,
+            score: record.Get(1)?,
+            abilities: Abilities {
+
+                evolve: record.Get(2)?,
+                destroy: record.Get(3)?,
+                restrict: record.Get(4)?
+            },
+            played,
+            deck: CardDeck::Load(reader)?
+        });
+    }
+}
+
+#[derive(Clone)]
+pub struct Game {
+
+    // Rules chosen before the deal.
+    config: GameConfig,
+
+    board: GameBoard,
+    log: ActionLog,
+
+    // Per side state.
+    player: SideState,
+    opponent: SideState,
+
+    // Turn tracking.
+    turn_number: u8,
+    current_side: Side,
+    phase: Phase,
+
+    // Every random outcome and every applied action since the deal. Together they are enough to replay the game.
+    chance: Chance,
+    history: Vec<Action>
+}
+
+// default Function:
+// - Description: Starts a game between the player and the computer.
+impl Default for Game {
+    fn default() -> Self {
+
+        return Game::New(&Side::Player.GetName(), &Side::Opponent.GetName());
+    }
+}
+
+impl Game {
+
+    // New Function:
+    // Parameters:
+    // - player_name: Name shown for the player side.
+    // - opponent_name: Name shown for the opponent side.
+    // Description: Deals the opening hands, chooses who goes first, and starts the first turn.
+    // Return: The new game.
+    pub fn New(player_name: &str, opponent_name: &str) -> Game {
+
+        return Game::NewWithChance(player_name, opponent_name, GameConfig::default(), Chance::default());
+    }
+
+    // NewWithChance Function:
+    // Parameters:
+    // - player_name: Name shown for the player side.
+    // - opponent_name: Name shown for the opponent side.
+    // - config: Rules of the game. Must be allowed by GameConfig::Check.
+    // - chance: Source of the random outcomes. Used to deal a game again from recorded outcomes.
+    // Description: Deals the opening hands, chooses who goes first, and starts the first turn.
+    // Return: The new game.
+    pub fn NewWithChance(player_name: &str, opponent_name: &str, config: GameConfig, chance: Chance) -> Game {
+
+        let mut game = Game {
+
+            board: GameBoard::New(config.board_size.0, config.board_size.1),
+            log: ActionLog::default(),
+
+            player: SideState {
+
+                name: String::from(player_name),
+                deck: CardDeck::New(&config.player_deck),
+                score: 0,
+                abilities: Abilities { evolve: 1, destroy: 1, restrict: 2 },
+                played: Vec::new()
+            },
+
+            opponent: SideState {
+
+                name: String::from(opponent_name),
+                deck: CardDeck::New(&config.opponent_deck),
+                score: 0,
+                abilities: Abilities { evolve: 1, destroy: 1, restrict: 2 },
+                played: Vec::new()
+            },
+
+            turn_number: 1,
+            current_side: Side::Player,
+            phase: Phase::Ability,
+
+            chance,
+            history: Vec::new(),
+
+            // Moved in last, since the board and decks above are made from it.
+            config
+        };
+
+        game.board.SetScoring(game.config.scoring);
+
+        // Draw the opening hands. A hand never holds more than the largest hand size.
+        for _ in 0..game.config.opening_hand.min(game.config.max_hand) {
+
+            game.player.deck.AddToHand(&mut game.chance);
+            game.opponent.deck.AddToHand(&mut game.chance);
+        }
+
+        // Generate a random number to determine who moves first.
+        let coin = game.chance.Pick(Stream::Coin, 2);
+        if coin != 0 {
+
+            game.current_side = Side::Opponent;
+        }
+
+        // The seed is shown so that the same game can be dealt again with --seed.
+        game.log.PushAction(format!("Seed: {}.", game.chance.GetSeed()), false);
+        game.log.PushAction(format!("{} is going first.", game.GetSideName(game.current_side)), false);
+        game.BeginTurn();
+
+        return game;
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Turn Actions
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // Validate Function:
+    // Parameters:
+    // - action: The action to check for the side whose turn it is.
+    // Description: Checks that an action can be applied without changing the game.
+    // Return: ActionError describing why the action cannot be used.
+    pub fn Validate(&self, action: &Action) -> Result<(), ActionError> {
+
+        if self.phase == Phase::GameOver {
+
+            return Err(ActionError::GameOver);
+        }
+
+        // Plays belong to the main phase. Everything else belongs to the ability phase.
+        let main_phase_action = matches!(action, Action::Play { .. });
+        if main_phase_action != (self.phase == Phase::Main) {
+
+            return Err(ActionError::WrongPhase);
+        }
+
+        let deck = self.GetDeck(self.current_side);
+        let abilities = self.GetAbilities(self.current_side);
+
+        match action {
+
+            Action::Play { element, tile, oxidation, order } => {
+
+                match deck.GetCard(element.clone()) {
+
+                    Some(card) => self.board.CheckBond(*tile, &card.GetAtom(Game::GetPlayBonds(&card, *oxidation)?), *order)?,
+                    None => return Err(ActionError::CardNotInHand),
+                }
+            },
+            Action::Destroy { tile } => {
+
+                Game::CheckAbility(abilities.destroy)?;
+                self.board.CheckDestroy(*tile)?;
+            },
+            Action::Restrict { a, b } => {
+
+                Game::CheckAbility(abilities.restrict)?;
+                self.board.CheckRestrict(*a, *b)?;
+            },
+            Action::Evolve { element } => {
+
+                Game::CheckAbility(abilities.evolve)?;
+                match deck.GetCard(element.clone()) {
+
+                    Some(card) => {
+
+                        if !card.CanEvolve() {
+
+                            return Err(ActionError::CannotEvolve);
+                        }
+                    },
+                    None => return Err(ActionError::CardNotInHand),
+                }
+            },
+            Action::Pass => (),
+        }
+
+        return Ok(());
+    }
+
+    // LegalActions Function:
+    // Description: Lists one action for each distinct outcome that the side whose turn it is can choose. Every action
+    //   listed passes Validate, but actions that Validate accepts with the same outcome as a listed action are left
+    //   out. (Example: a play given the bond order it would form anyway)
+    // - Main phase: Every atom in hand on every tile it can bond to. Duplicate atoms in hand are listed once, and
+    //   transition metals are listed with each of their oxidation states. Plays that would form a double or triple bond
+    //   are also listed with each smaller bond order.
+    // - Ability phase: Pass, every pair of tiles that can be restricted, one destroy per compound on the
+    //   board (using the parent tile), and every atom in hand that can be evolved.
+    // Return: Vector of actions. Empty once the game is over.
+    pub fn LegalActions(&self) -> Vec<Action> {
+
+        let mut actions: Vec<Action> = Vec::new();
+
+        if self.phase == Phase::GameOver {
+
+            return actions;
+        }
+
+        // Names of the atoms in hand without duplicates.
+        let deck = self.GetDeck(self.current_side);
+        let mut names: Vec<String> = Vec::new();
+        for i in 0..deck.GetHandSize() {
+
+            let name = deck.GetFromIndex(i).name;
+            if !names.contains(&name) {
+
+                names.push(name);
+            }
+        }
+
+        // Every tile on the board.
+        let mut tiles: Vec<(usize, usize)> = Vec::new();
+        for i in 0..self.board.GetWidth() {
+
+            for j in 0..self.board.GetHeight() {
+
+                tiles.push((i, j));
+            }
+        }
+
+        if self.phase == Phase::Main {
+
+            for name in names.iter() {
+
+                // Transition metals are listed once for each oxidation state.
+                let options = deck.GetCard(name.clone()).unwrap().GetBondOptions();
+                let states: Vec<Option<u16>> = match options.len() {
+
+                    1 => vec![None],
+                    _ => options.into_iter().map(Some).collect(),
+                };
+
+                for oxidation in states.iter() {
+
+                    for tile in tiles.iter() {
+
+                        let action = Action::Play { element: name.clone(), tile: *tile, oxidation: *oxidation, order: None };
+                        if self.Validate(&action).is_ok() {
+
+                            actions.push(action);
+
+                            // A chosen order only forms the bond with the owner of the zone, so smaller bonds leave bonds
+                            // free for other atoms. The largest order is only different when other neighbors would bond.
+                            let preview = self.PreviewPlay(name, *tile, *oxidation, None).unwrap();
+                            let largest_order = match preview.extra_bonds {
+
+                                0 => preview.bonds_formed,
+                                _ => preview.bonds_formed + 1,
+                            };
+                            for order in 1..largest_order {
+
+                                actions.push(Action::Play { element: name.clone(), tile: *tile, oxidation: *oxidation, order: Some(order) });
+                            }
+                        }
+                    }
+                }
+            }
+
+            return actions;
+        }
+
+        actions.push(Action::Pass);
+        let abilities = self.GetAbilities(self.current_side);
+
+        // Restrict is symmetric, so each pair is only listed once.
+        if abilities.restrict > 0 {
+
+            for a in 0..tiles.len() {
+
+                for b in (a + 1)..tiles.len() {
+
+                    if self.board.CheckRestrict(tiles[a], tiles[b]).is_ok() {
+
+                        actions.push(Action::Restrict { a: tiles[a], b: tiles[b] });
+                    }
+                }
+            }
+        }
+
+        // Every atom within a compound destroys the same compound.
+        if abilities.destroy > 0 {
+
+            let mut parents: Vec<(usize, usize)> = Vec::new();
+            for tile in tiles.iter() {
+
+                match self.board.CheckDestroy(*tile) {
+
+                    Ok(parent) => {
+
+                        if !parents.contains(&parent) {
+
+                            parents.push(parent);
+                            actions.push(Action::Destroy { tile: parent });
+                        }
+                    },
+                    Err(_) => (),
+                }
+            }
+        }
+
+        if abilities.evolve > 0 {
+
+            for name in names {
+
+                let action = Action::Evolve { element: name };
+                if self.Validate(&action).is_ok() {
+
+                    actions.push(action);
+                }
+            }
+        }
+
+        return actions;
+    }
+
+    // PreviewPlay Function:
+    // Parameters:
+    // - element: Name of an atom in the current side's hand.
+    // - tile: Tile the atom would be played on.
+    // - oxidation: Oxidation state chosen for a transition metal.
+    // - order: Bond order chosen for the bond. None forms the largest possible bond.
+    // Description: Works out the result of a play without changing the game.
+    // Return: BondPreview struct. ActionError if the play is not valid.
+    pub fn PreviewPlay(&self, element: &str, tile: (usize, usize), oxidation: Option<u16>, order: Option<u16>) -> Result<BondPreview, ActionError> {
+
+        self.Validate(&Action::Play { element: String::from(element), tile, oxidation, order })?;
+
+        let card = self.GetDeck(self.current_side).GetCard(String::from(element)).unwrap();
+        let bonds = Game::GetPlayBonds(&card, oxidation)?;
+        let preview = self.board.SimulateBond(tile, &card.GetAtom(bonds), order)?;
+
+        return Ok(preview);
+    }
+
+    // Apply Function:
+    // Parameters:
+    // - action: The action to use for the side whose turn it is.
+    // Description: Validates and applies an action. Plays end the turn. Other actions end the ability phase, and also
+    //   end the turn if the side has nowhere to play, which can happen once a small board fills up.
+    // Return: Outcome of the action. ActionError if the action could not be used, in which case nothing changes.
+    pub fn Apply(&mut self, action: Action) -> Result<Outcome, ActionError> {
+
+        self.Validate(&action)?;
+
+        let side = self.current_side;
+        let name = self.GetSideName(side);
+        let mut points: Option<u16> = None;
+        let recorded = action.clone();
+
+        match action {
+
+            Action::Play { element, tile, oxidation, order } => {
+
+                // The card leaves the hand once it is on the board.
+                let card: Card = self.GetDeck(side).GetCard(element.clone()).unwrap();
+                let bonds = Game::GetPlayBonds(&card, oxidation)?;
+                points = self.board.Bond(tile, &card.GetAtom(bonds), self.turn_number, &mut self.chance, order)?;
+                self.GetSideState(side).deck.RemoveCard(element.clone());
+
+                // Each bond is classified by its order and the difference in electronegativity of its atoms.
+                for bond in self.board.GetBonds(tile) {
+
+                    self.log.PushAction(format!("{} formed a {} bond. ({})", name, bond.GetOrderName(), bond.bond_type.GetName()), false);
+                }
+
+                // The chosen oxidation state cannot be seen on the board, so it is written to the log.
+                if card.GetBondOptions().len() > 1 {
+
+                    self.log.PushAction(format!("{} played {} with {} bonds.", name, element, bonds), false);
+                }
+
+                // An evolved atom is counted as the card it was dealt as, since that is the card that left the deck.
+                self.GetSideState(side).played.push(card.origin.clone());
+
+                // If points exist, a neutral compound was created.
+                if points != None {
+
+                    let score = &mut self.GetSideState(side).score;
+                    *score = score.saturating_add(points.unwrap());
+                    self.log.PushAction(format!("{} completed a compound!", name), false);
+                }
+            },
+            Action::Destroy { tile } => {
+
+                self.board.Destroy(tile)?;
+                self.GetSideState(side).abilities.destroy -= 1;
+                self.log.PushAction(format!("{} has destroyed a compound.", name), false);
+            },
+            Action::Restrict { a, b } => {
+
+                self.board.Restrict(a, b)?;
+                self.GetSideState(side).abilities.restrict -= 1;
+                self.log.PushAction(format!("{} used restrict ability.", name), false);
+            },
+            Action::Evolve { element } => {
+
+                self.GetSideState(side).deck.EvolveCard(element);
+                self.GetSideState(side).abilities.evolve -= 1;
+                self.log.PushAction(format!("{} has evolved an atom.", name), false);
+            },
+            Action::Pass => {
+
+                self.log.PushAction(format!("{} has passed their ability phase.", name), false);
+            },
+        }
+
+        self.history.push(recorded);
+
+        // A play ends the turn. Any other action moves the turn into the main phase.
+        let mut turn_ended = self.phase == Phase::Main;
+        if turn_ended {
+
+            self.EndTurn();
+        }
+        else {
+
+            self.phase = Phase::Main;
+
+            if self.GetDeck(side).GetHandSize() == 0 {
+
+                self.log.PushAction(format!("{} has no cards left to play.", name), false);
+                self.EndTurn();
+                turn_ended = true;
+            }
+            else if !self.CanPlay() {
+
+                self.log.PushAction(format!("{} has nowhere to play.", name), false);
+                self.EndTurn();
+                turn_ended = true;
+            }
+        }
+
+        return Ok(Outcome {
+
+            side,
+            points,
+            turn_ended,
+            game_over: self.IsOver()
+        });
+    }
+
+    // Determinize Function:
+    // Parameters:
+    // - viewer: The side whose point of view is kept.
+    // - sample: Number of the guess. Each guess made at the same point of the game should use a different number.
+    // Description: Replaces the hidden hand and deck of the other side with a random guess made from the cards that
+    //   the viewer has not seen. Used on copies of the game so that the computer does not search using hidden cards.
+    //   The random generator is replaced as well, since a copy of it would draw the same cards as the real game.
+    pub fn Determinize(&mut self, viewer: Side, sample: u64) {
+
+        self.chance.Scramble(self.ComputerSeed(sample));
+
+        let list = self.GetDeckList(viewer.Other()).clone();
+        let (other, chance) = self.GetSideAndChance(viewer.Other());
+        other.deck.Resample(&list, &other.played, chance);
+    }
+
+    // ComputerSeed Function:
+    // Parameters:
+    // - salt: Number that tells apart seeds made at the same point of the game.
+    // Description: Makes a seed for the computer's own random choices from the seed of the game, the turn number, and
+    //   the number of random outcomes picked so far. The same seed and the same actions give the computer the same
+    //   choices, so games against it can be reproduced, without it being able to copy the order of the real draws.
+    pub fn ComputerSeed(&self, salt: u64) -> u64 {
+
+        let position = ((self.turn_number as u64) << 32) + (self.chance.GetOutcomes().len() as u64);
+        return self.chance.GetSeed().wrapping_add(position.wrapping_add(salt).wrapping_add(1).wrapping_mul(COMPUTER_SALT));
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Getters
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // GetUnseenCards Function:
+    // Parameters:
+    // - viewer: The side that is looking at the other side's cards.
+    // Description: Returns the cards that the other side could still be holding or drawing, as far as the viewer knows.
+    pub fn GetUnseenCards(&self, viewer: Side) -> Vec<Card> {
+
+        match viewer {
+
+            Side::Player => return CardDeck::Unseen(&self.config.opponent_deck, &self.opponent.played),
+            Side::Opponent => return CardDeck::Unseen(&self.config.player_deck, &self.player.played),
+        }
+    }
+
+    // GetBoard Function:
+    // Description: Returns the game board.
+    pub fn GetBoard(&self) -> &GameBoard {
+
+        return &self.board;
+    }
+
+    // GetLog Function:
+    // Description: Returns the action log.
+    pub fn GetLog(&self) -> &ActionLog {
+
+        return &self.log;
+    }
+
+    // PushAction Function:
+    // Parameters:
+    // - action: String description of the action.
+    // - error: flag if the action is an error action.
+    // Description: Records an event from outside of the engine, such as an input error, in the action log.
+    pub fn PushAction(&mut self, action: String, error: bool) {
+
+        self.log.PushAction(action, error);
+    }
+
+    // GetDeck Function:
+    // Parameters:
+    // - side: The side that owns the deck.
+    // Description: Returns the deck and hand of a side.
+    pub fn GetDeck(&self, side: Side) -> &CardDeck {
+
+        match side {
+
+            Side::Player => return &self.player.deck,
+            Side::Opponent => return &self.opponent.deck,
+        }
+    }
+
+    // GetReplay Function:
+    // Description: Returns everything needed to play this game again from the deal.
+    pub fn GetReplay(&self) -> Replay {
+
+        return Replay {
+
+            player_name: self.player.name.clone(),
+            opponent_name: self.opponent.name.clone(),
+            config: self.config.clone(),
+            seed: self.chance.GetSeed(),
+            outcomes: self.chance.GetOutcomes().to_vec(),
+            actions: self.history.clone()
+        };
+    }
+
+    // GetSideName Function:
+    // Parameters:
+    // - side: The side to get the name of.
+    // Description: Returns the name shown for a side.
+    pub fn GetSideName(&self, side: Side) -> String {
+
+        match side {
+
+            Side::Player => return self.player.name.clone(),
+            Side::Opponent => return self.opponent.name.clone(),
+        }
+    }
+
+    // GetScore Function:
+    // Parameters:
+    // - side: The side to get the score of.
+    // Description: Returns the score of a side.
+    pub fn GetScore(&self, side: Side) -> u16 {
+
+        match side {
+
+            Side::Player => return self.player.score,
+            Side::Opponent => return self.opponent.score,
+        }
+    }
+
+    // GetAbilities Function:
+    // Parameters:
+    // - side: The side to get the ability counters of.
+    // Description: Returns the remaining abilities of a side.
+    pub fn GetAbilities(&self, side: Side) -> &Abilities {
+
+        match side {
+
+            Side::Player => return &self.player.abilities,
+            Side::Opponent => return &self.opponent.abilities,
+        }
+    }
+
+    // GetCurrentSide Function:
+    // Description: Returns the side whose turn it is.
+    pub fn GetCurrentSide(&self) -> Side {
+
+        return self.current_side;
+    }
+
+    // GetPhase Function:
+    // Description: Returns the phase of the current turn.
+    pub fn GetPhase(&self) -> Phase {
+
+        return self.phase;
+    }
+
+    // GetTurnNumber Function:
+    // Description: Returns the number of the current turn. Starts at one.
+    pub fn GetTurnNumber(&self) -> u8 {
+
+        return self.turn_number;
+    }
+
+    // IsOver Function:
+    // Description: Returns true once all turns have been played.
+    pub fn IsOver(&self) -> bool {
+
+        return self.phase == Phase::GameOver;
+    }
+
+    // GetWinner Function:
+    // Description: Returns the side with the higher score. None if the game is tied.
+    pub fn GetWinner(&self) -> Option<Side> {
+
+        if self.player.score > self.opponent.score {
+
+            return Some(Side::Player);
+        }
+        else if self.opponent.score > self.player.score {
+
+            return Some(Side::Opponent);
+        }
+
+        return None;
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Saving
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // SaveFile Function:
+    // Parameters:
+    // - path: The file to write.
+    // Description: Writes the whole state of the game to a plain text file. The file is replaced if it exists.
+    pub fn SaveFile(&self, path: &str) -> Result<(), SaveError> {
+
+        match fs::write(path, self.SaveText()) {
+
+            Ok(_) => return Ok(()),
+            Err(error) => return Err(SaveError::Io(format!("Could not write \"{}\". {}", path, error))),
+        }
+    }
+
+    // SaveText Function:
+    // Description: Returns the text of the save file written by SaveFile.
+    fn SaveText(&self) -> String {
+
+        let mut writer = SaveWriter::New(SAVE_HEADER);
+
+        writer.Write("turn", &[self.turn_number.ToField(), self.current_side.ToField(), self.phase.ToField()]);
+        self.player.Save(&mut writer);
+        self.opponent.Save(&mut writer);
+        self.board.Save(&mut writer);
+        self.log.Save(&mut writer);
+
+        // The replay is kept so that a loaded game can still be replayed from the deal.
+        self.GetReplay().Save(&mut writer);
+
+        return writer.Finish();
+    }
+
+    // LoadFile Function:
+    // Parameters:
+    // - path: The file to read.
+    // Description: Reads a game written by SaveFile. The game continues from the exact point it was saved.
+    // Return: The game. Error if the file cannot be read or is not a save file.
+    pub fn LoadFile(path: &str) -> Result<Game, SaveError> {
+
+        let text = match fs::read_to_string(path) {
+
+            Ok(text) => text,
+            Err(error) => return Err(SaveError::Io(format!("Could not read \"{}\". {}", path, error))),
+        };
+
+        let mut reader = SaveReader::New(&text, SAVE_HEADER)?;
+
+        let record = reader.Read("turn")?;
+        let mut game = Game {
+
+            config: GameConfig::default(),
+            turn_number: record.Get(0)?,
+            current_side: record.Get(1)?,
+            phase: record.Get(2)?,
+            player: SideState::Load(&mut reader)?,
+            opponent: SideState::Load(&mut reader)?,
+            board: GameBoard::Load(&mut reader)?,
+            log: ActionLog::Load(&mut reader)?,
+            chance: Chance::default(),
+            history: Vec::new()
+        };
+
+        // The rules are taken from the replay, which has them from the deal.
+        let replay = Replay::Load(&mut reader)?;
+        game.config = replay.config;
+        game.board.SetScoring(game.config.scoring);
+        game.chance = Chance::Resume(replay.seed, replay.outcomes);
+        game.history = replay.actions;
+
+        return Ok(game);
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Turn Sequencing
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // BeginTurn Function:
+    // Description: The current side draws a card and enters the ability phase.
+    fn BeginTurn(&mut self) {
+
+        let (draw, max_hand) = (self.config.draw_per_turn, self.config.max_hand as usize);
+
+        // Cards are not drawn into a full hand.
+        let (current, chance) = self.GetSideAndChance(self.current_side);
+        for _ in 0..draw {
+
+            if current.deck.GetHandSize() < max_hand {
+
+                current.deck.AddToHand(chance);
+            }
+        }
+
+        self.phase = Phase::Ability;
+    }
+
+    // EndTurn Function:
+    // Description: Resolves the end of turn on the board and passes the turn to the other side.
+    fn EndTurn(&mut self) {
+
+        self.board.EndTurn();
+
+        if self.turn_number >= self.config.turns {
+
+            self.phase = Phase::GameOver;
+            self.log.PushAction(String::from("Game over!"), false);
+            return;
+        }
+
+        self.turn_number += 1;
+        self.current_side = self.current_side.Other();
+        self.BeginTurn();
+    }
+
+    // GetPlayBonds Function:
+    // Parameters:
+    // - card: The atom being played.
+    // - oxidation: Oxidation state chosen for the atom.
+    // Description: Works out how many bonds an atom is played with. Atoms use their usual number of bonds when no
+    //   oxidation state is chosen.
+    // Return: Number of bonds. ActionError if the atom does not have the chosen oxidation state.
+    fn GetPlayBonds(card: &Card, oxidation: Option<u16>) -> Result<u16, ActionError> {
+
+        match oxidation {
+
+            Some(state) => {
+
+                if !card.GetBondOptions().contains(&state) {
+
+                    return Err(ActionError::InvalidOxidation);
+                }
+
+                return Ok(state);
+            },
+            None => return Ok(card.number_of_bonds),
+        }
+    }
+
+    // CanPlay Function:
+    // Description: Returns true if the side whose turn it is can play any atom in hand on any tile. Transition metals
+    //   are checked with each of their oxidation states.
+    fn CanPlay(&self) -> bool {
+
+        let deck = self.GetDeck(self.current_side);
+        for index in 0..deck.GetHandSize() {
+
+            let card = deck.GetFromIndex(index);
+            for bonds in card.GetBondOptions() {
+
+                let atom = card.GetAtom(bonds);
+                for i in 0..self.board.GetWidth() {
+
+                    for j in 0..self.board.GetHeight() {
+
+                        if self.board.CheckBond((i, j), &atom, None).is_ok() {
+
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        return false;
+    }
+
+    // CheckAbility Function:
+    // Parameters:
+    // - remaining: Remaining uses of the ability being checked.
+    // Description: Checks that there is a use of an ability remaining.
+    fn CheckAbility(remaining: u8) -> Result<(), ActionError> {
+
+        if remaining == 0 {
+
+            return Err(ActionError::NoAbilityRemaining);
+        }
+
+        return Ok(());
+    }
+
+    // GetDeckList Function:
+    // Parameters:
+    // - side: The side that owns the deck list.
+    // Description: Returns the deck list that a side's deck was made from.
+    fn GetDeckList(&self, side: Side) -> &DeckList {
+
+        match side {
+
+            Side::Player => return &self.config.player_deck,
+            Side::Opponent => return &self.config.opponent_deck,
+        }
+    }
+
+    // GetSideState Function:
+    // Parameters:
+    // - side: The side to get.
+    // Description: Returns a mutable refference to the state of a side.
+    fn GetSideState(&mut self, side: Side) -> &mut SideState {
+
+        match side {
+
+            Side::Player => return &mut self.player,
+            Side::Opponent => return &mut self.opponent,
+        }
+    }
+
+    // GetSideAndChance Function:
+    // Parameters:
+    // - side: The side to get.
+    // Description: Returns the state of a side along with the source of random outcomes, for drawing cards.
+    fn GetSideAndChance(&mut self, side: Side) -> (&mut SideState, &mut Chance) {
+
+        match side {
+
+            Side::Player => return (&mut self.player, &mut self.chance),
+            Side::Opponent => return (&mut self.opponent, &mut self.chance),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Std modules.
+use std::fs;
+
+// My modules.
+use crate::chance::Chance;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+use super::{Game, GameConfig, Action};
+
+// First line of every replay file. Changed if the format changes.
+const REPLAY_HEADER: &str = "fcgreen6 replay 7";
+
+// Struct holding everything needed to play a game again from the deal: the names of both sides, the rules of the
+// game, the seed, every random outcome in the order it was picked, and every action in the order it was applied.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Replay {
+
+    pub player_name: String,
+    pub opponent_name: String,
+    pub config: GameConfig,
+    pub seed: u64,
+    pub outcomes: Vec<usize>,
+    pub actions: Vec<Action>
+}
+
+impl Replay {
+
+    // Steps Function:
+    // Description: Deals the game again using the recorded outcomes and applies every recorded action.
+    // Return: The game after the deal followed by the game after each action. Error if an action could not be applied,
+    //   which means the replay does not belong to this version of the game.
+    pub fn Steps(&self) -> Result<Vec<Game>, String> {
+
+        let mut game = Game::NewWithChance(&self.player_name, &self.opponent_name, self.config.clone(), Chance::FromOutcomes(self.seed, self.outcomes.clone()));
+        let mut steps: Vec<Game> = vec![game.clone()];
+
+        for (index, action) in self.actions.iter().enumerate() {
+
+            match game.Apply(action.clone()) {
+
+                Ok(_) => steps.push(game.clone()),
+                Err(error) => return Err(format!("Error: Step {} of the replay could not be played. {}", index + 1, error)),
+            }
+        }
+
+        return Ok(steps);
+    }
+
+    // Save Function:
+    // Parameters:
+    // - writer: The file being written.
+    // Description: Writes the names, the rules, the seed, the outcomes, and the number of actions followed by each
+    //   action.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("names", &[self.player_name.ToField(), self.opponent_name.ToField()]);
+        self.config.Save(writer);
+        writer.Write("seed", &[self.seed.ToField()]);
+
+        let outcomes: Vec<String> = self.outcomes.iter().map(|outcome| outcome.ToField()).collect();
+        writer.Write("chance", &outcomes);
+
+        writer.Write("actions", &[self.actions.len().ToField()]);
+        for action in self.actions.iter() {
+
+            writer.Write("action", &[action.ToField()]);
+        }
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The file being read.
+    // Description: Reads a replay written by Save.
+    // Return: The replay. Error if the rules are not allowed.
+    pub fn Load(reader: &mut SaveReader) -> Result<Replay, SaveError> {
+
+        let names = reader.Read("names")?;
+        let config = GameConfig::Load(reader)?;
+        let seed: u64 = reader.Read("seed")?.Get(0)?;
+        let outcomes: Vec<usize> = reader.Read("chance")?.GetAll(0)?;
+
+        let mut actions: Vec<Action> = Vec::new();
+        let length: usize = reader.Read("actions")?.Get(0)?;
+        for _ in 0..length {
+
+            actions.push(reader.Read("action")?.Get(0)?);
+        }
+
+        return Ok(Replay {
+
+            player_name: names.Get(0)?,
+            opponent_name: names.Get(1)?,
+            config,
+            seed,
+            outcomes,
+            actions
+        });
+    }
+
+    // SaveFile Function:
+    // Parameters:
+    // - path: The file to write.
+    // Description: Writes the replay to a plain text file. The file is replaced if it exists.
+    pub fn SaveFile(&self, path: &str) -> Result<(), SaveError> {
+
+        let mut writer = SaveWriter::New(REPLAY_HEADER);
+        self.Save(&mut writer);
+
+        match fs::write(path, writer.Finish()) {
+
+            Ok(_) => return Ok(()),
+            Err(error) => return Err(SaveError::Io(format!("Could not write \"{}\". {}", path, error))),
+        }
+    }
+
+    // LoadFile Function:
+    // Parameters:
+    // - path: The file to read.
+    // Description: Reads a replay written by SaveFile.
+    pub fn LoadFile(path: &str) -> Result<Replay, SaveError> {
+
+        let text = match fs::read_to_string(path) {
+
+            Ok(text) => text,
+            Err(error) => return Err(SaveError::Io(format!("Could not read \"{}\". {}", path, error))),
+        };
+
+        let mut reader = SaveReader::New(&text, REPLAY_HEADER)?;
+        return Replay::Load(&mut reader);
+    }
+}
+
+// Enum describing a move between the steps of a replay.
+// - Next: The step after the current one.
+// - Back: The step before the current one.
+// - First: The deal.
+// - Last: The end of the replay.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReplayStep {
+    Next,
+    Back,
+    First,
+    Last,
+}
+
+impl ReplayStep {
+
+    // FromName Function:
+    // Parameters:
+    // - name: Command typed by the user.
+    // Description: Converts a command into a move between steps.
+    // Return: None if the command is not a move.
+    pub fn FromName(name: &str) -> Option<ReplayStep> {
+
+        match name {
+
+            "next" => return Some(ReplayStep::Next),
+            "back" => return Some(ReplayStep::Back),
+            "first" => return Some(ReplayStep::First),
+            "last" => return Some(ReplayStep::Last),
+            _ => return None,
+        }
+    }
+
+    // Move Function:
+    // Parameters:
+    // - index: The step being shown.
+    // - count: Number of steps in the replay. Must be at least one.
+    // Description: Finds the step to show next. Moves past either end stay on the end.
+    pub fn Move(self, index: usize, count: usize) -> usize {
+
+        match self {
+
+            ReplayStep::Next => return (index + 1).min(count - 1),
+            ReplayStep::Back => return index.saturating_sub(1),
+            ReplayStep::First => return 0,
+            ReplayStep::Last => return count - 1,
+        }
+    }
+}
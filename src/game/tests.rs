@@ -0,0 +1,835 @@
+// Std modules.
+use std::env;
+use std::fs;
+
+// My modules.
+use super::{Game, GameConfig, Side, Phase, Action, ActionError, Replay, ReplayStep, UndoHistory, UndoRule, UndoError};
+use crate::chance::Chance;
+use crate::card_deck::Card;
+use crate::computer::Difficulty;
+use crate::save_file::{SaveWriter, SaveError};
+use crate::game_board::TileState;
+
+// TempPath Function:
+// Parameters:
+// - name: Name of the file, which should differ between tests since they run at the same time.
+// Description: Returns a path within the temporary directory for a test to write to.
+fn TempPath(name: &str) -> String {
+
+    return env::temp_dir().join(format!("fcgreen6-{}-{}", std::process::id(), name)).to_string_lossy().into_owned();
+}
+
+// WriteTemp Function:
+// Parameters:
+// - name: Name of the file.
+// - text: Text written to the file.
+// Description: Writes a temporary file.
+// Return: Path of the file.
+fn WriteTemp(name: &str, text: &str) -> String {
+
+    let path = TempPath(name);
+    fs::write(&path, text).unwrap();
+    return path;
+}
+
+// SaveText Function:
+// Parameters:
+// - game: The game to save.
+// - name: Name of the temporary save file.
+// Description: Saves a game and returns the text of the save file.
+fn SaveText(game: &Game, name: &str) -> String {
+
+    let path = TempPath(name);
+    game.SaveFile(&path).unwrap();
+    return fs::read_to_string(&path).unwrap();
+}
+
+// MixedConfig Function:
+// Parameters:
+// - name: Name of the temporary deck list file.
+// Description: Rules with both decks holding a transition metal, an ion, and a noble gas as well as main group atoms.
+fn MixedConfig(name: &str) -> GameConfig {
+
+    let path = WriteTemp(name, "# Every kind of card.\nFe 3\nOH 3\nNe 2\nLi 3\nO 3\nC 2\n");
+
+    let mut config = GameConfig::default();
+    config.Set("transition_metals", "on").unwrap();
+    config.Set("player_deck", &path).unwrap();
+    config.Set("opponent_deck", &path).unwrap();
+    return config;
+}
+
+// PlayTurn Function:
+// Parameters:
+// - game: The game to take a turn in.
+// - preferred: Atoms and oxidation states to play, best first.
+// Description: Passes the ability phase and plays the first preferred atom that can be played. Falls back to the
+//   first legal action if none of them can.
+fn PlayTurn(game: &mut Game, preferred: &[(&str, Option<u16>)]) {
+
+    if game.GetPhase() == Phase::Ability {
+
+        game.Apply(Action::Pass).unwrap();
+    }
+
+    if game.GetPhase() != Phase::Main {
+
+        return;
+    }
+
+    let legal = game.LegalActions();
+    for (name, oxidation) in preferred {
+
+        let wanted = legal.iter().find(|action| match action {
+
+            Action::Play { element, oxidation: played, order, .. } => (element == name) && (played == oxidation) && (*order == None),
+            _ => false,
+        });
+
+        match wanted {
+
+            Some(action) => {
+
+                game.Apply(action.clone()).unwrap();
+                return;
+            },
+            None => (),
+        }
+    }
+
+    game.Apply(legal[0].clone()).unwrap();
+}
+
+// HasSymbol Function:
+// Parameters:
+// - game: The game to look at.
+// - symbol: Symbol of an atom.
+// Description: Returns true if any tile on the board shows the symbol.
+fn HasSymbol(game: &Game, symbol: &str) -> bool {
+
+    let board = game.GetBoard();
+    for i in 0..board.GetWidth() {
+
+        for j in 0..board.GetHeight() {
+
+            if board.FormatTile((i, j)).contains(symbol) {
+
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+// BoardText Function:
+// Parameters:
+// - game: The game to look at.
+// Description: Returns the board as it is written to a save file, so that two boards can be compared.
+fn BoardText(game: &Game) -> String {
+
+    let mut writer = SaveWriter::New("board");
+    game.board.Save(&mut writer);
+    return writer.Finish();
+}
+
+// FirstPlay Function:
+// Parameters:
+// - game: The game to look at. Must be in the main phase.
+// Description: Returns the first legal play.
+fn FirstPlay(game: &Game) -> Action {
+
+    return game.LegalActions().into_iter().find(|action| matches!(action, Action::Play { .. })).unwrap();
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Saving
+//-----------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn SaveLoadSaveGivesTheSameFile() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("save-deck.txt"), Chance::Seeded(11));
+    // The atom each turn tries first, alternating between the two sides.
+    let turns = [("Fe", Some(3)), ("Li", None), ("OH", None), ("O", None), ("Fe", Some(3)), ("Ne", None)];
+    for preferred in turns {
+
+        PlayTurn(&mut game, &[preferred]);
+    }
+
+    // Take the last action back, so the save is made part way through the undo history.
+    let mut history = UndoHistory::New(UndoRule::Always);
+    history.Record(game.clone());
+    PlayTurn(&mut game, &[("OH", None)]);
+    history.Undo(&mut game).unwrap();
+
+    assert!(HasSymbol(&game, "Fe"));
+    assert!(HasSymbol(&game, "OH"));
+    assert!(HasSymbol(&game, "Ne"));
+
+    let saved = SaveText(&game, "round-trip-1.txt");
+    let loaded = Game::LoadFile(&TempPath("round-trip-1.txt")).unwrap();
+    assert_eq!(SaveText(&loaded, "round-trip-2.txt"), saved);
+}
+
+#[test]
+fn LoadedGameContinuesTheSameWay() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("continue-deck.txt"), Chance::Seeded(12));
+    let preferred = [("C", None), ("O", None), ("Li", None)];
+    for _ in 0..3 {
+
+        PlayTurn(&mut game, &preferred);
+    }
+
+    game.SaveFile(&TempPath("continue.txt")).unwrap();
+    let mut loaded = Game::LoadFile(&TempPath("continue.txt")).unwrap();
+
+    // Later draws come from the seed, so both games draw the same cards.
+    for _ in 0..4 {
+
+        PlayTurn(&mut game, &preferred);
+        PlayTurn(&mut loaded, &preferred);
+    }
+
+    assert_eq!(SaveText(&loaded, "continue-2.txt"), SaveText(&game, "continue-1.txt"));
+}
+
+#[test]
+fn BadSaveFieldIsAnError() {
+
+    let game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(13));
+    let saved = SaveText(&game, "bad-field.txt");
+
+    // The turn number is the first field of the second line.
+    let broken = saved.replacen("turn\t1\t", "turn\tone\t", 1);
+    let path = WriteTemp("bad-field-broken.txt", &broken);
+    match Game::LoadFile(&path) {
+
+        Err(SaveError::Format { line, .. }) => assert_eq!(line, 2),
+        _ => panic!("Expected a format error."),
+    }
+
+    // A file cut off part way through is also an error.
+    let lines: Vec<&str> = saved.lines().collect();
+    let path = WriteTemp("bad-field-short.txt", &lines[..lines.len() / 2].join("\n"));
+    assert!(matches!(Game::LoadFile(&path), Err(SaveError::Format { .. })));
+
+    // So is a file that is not a save file.
+    let path = WriteTemp("bad-field-header.txt", "fcgreen6 replay 7\n");
+    assert!(matches!(Game::LoadFile(&path), Err(SaveError::Format { line: 1, .. })));
+
+    assert!(matches!(Game::LoadFile(&TempPath("missing.txt")), Err(SaveError::Io(_))));
+}
+
+#[test]
+fn BadBondOrderIsAnError() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(14));
+    for _ in 0..4 {
+
+        PlayTurn(&mut game, &[("C", None), ("O", None), ("H", None)]);
+    }
+
+    let saved = SaveText(&game, "bad-bond.txt");
+    let bond_line = saved.lines().position(|line| line.starts_with("bond\t")).expect("Expected a bond within the save.");
+
+    // Bond orders are from one to three.
+    let mut lines: Vec<String> = saved.lines().map(String::from).collect();
+    let mut fields: Vec<&str> = lines[bond_line].split('\t').collect();
+    fields[3] = "4";
+    lines[bond_line] = fields.join("\t");
+
+    let path = WriteTemp("bad-bond-broken.txt", &lines.join("\n"));
+    match Game::LoadFile(&path) {
+
+        Err(SaveError::Format { line, .. }) => assert_eq!(line, bond_line + 1),
+        _ => panic!("Expected a format error."),
+    }
+}
+
+// BreakField Function:
+// Parameters:
+// - saved: Text of a save file.
+// - start: Start of the first line to change, such as "tile\tParent\t".
+// - field: Position of the field to change after the key.
+// - value: Text written over the field.
+// - name: Name of the temporary file.
+// Description: Changes one field of a save file and writes the result to a temporary file.
+// Return: Path of the file and the number of the line that was changed.
+fn BreakField(saved: &str, start: &str, field: usize, value: &str, name: &str) -> (String, usize) {
+
+    let mut lines: Vec<String> = saved.lines().map(String::from).collect();
+    let index = lines.iter().position(|line| line.starts_with(start)).expect("Expected the line within the save.");
+
+    let mut fields: Vec<&str> = lines[index].split('\t').collect();
+    fields[field + 1] = value;
+    lines[index] = fields.join("\t");
+
+    return (WriteTemp(name, &lines.join("\n")), index + 1);
+}
+
+#[test]
+fn BadTileIsAnError() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(14));
+    for _ in 0..4 {
+
+        PlayTurn(&mut game, &[("C", None), ("O", None), ("H", None)]);
+    }
+    let saved = SaveText(&game, "bad-tile.txt");
+
+    // A parent tile without an atomic sum, a bond zone pointing off the board, and a bond between tiles off the
+    // board are each refused on their own line.
+    let broken = [
+        BreakField(&saved, "tile\tParent\t", 11, "-", "bad-tile-sum.txt"),
+        BreakField(&saved, "tile\tAtom\t", 5, "-", "bad-tile-parent.txt"),
+        BreakField(&saved, "tile\tBond\t", 4, "99,0", "bad-tile-zone.txt"),
+        BreakField(&saved, "tile\tAtom\t", 5, "0,99", "bad-tile-off.txt"),
+        BreakField(&saved, "bond\t", 0, "99,99", "bad-tile-bond.txt"),
+    ];
+
+    for (path, line) in broken {
+
+        match Game::LoadFile(&path) {
+
+            Err(SaveError::Format { line: error_line, .. }) => assert_eq!(error_line, line),
+            _ => panic!("Expected a format error."),
+        }
+    }
+
+    // An atom whose parent is an empty tile on the board is found once every tile has been read.
+    let empty = game.GetBoard().FindEmptyZone().unwrap();
+    let (path, _) = BreakField(&saved, "tile\tAtom\t", 5, &format!("{},{}", empty.0, empty.1), "bad-tile-empty.txt");
+    assert!(matches!(Game::LoadFile(&path), Err(SaveError::Format { .. })));
+}
+
+#[test]
+fn EveryPositionLoads() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("every-position-deck.txt"), Chance::Seeded(15));
+    let path = TempPath("every-position.txt");
+
+    WalkGame(&mut game, |game| {
+
+        game.SaveFile(&path).unwrap();
+        Game::LoadFile(&path).unwrap();
+    });
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Replays
+//-----------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ReplayMatchesTheLiveGame() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("replay-deck.txt"), Chance::Seeded(21));
+    let mut player = Difficulty::Medium.CreateStrategy();
+    let mut opponent = Difficulty::Easy.CreateStrategy();
+
+    // The board and both scores after the deal and after every action.
+    let mut live: Vec<(String, u16, u16)> = vec![(BoardText(&game), 0, 0)];
+    while !game.IsOver() {
+
+        let action = match game.GetCurrentSide() {
+
+            Side::Player => player.ChooseAction(&game),
+            Side::Opponent => opponent.ChooseAction(&game),
+        };
+
+        game.Apply(action).unwrap();
+        live.push((BoardText(&game), game.GetScore(Side::Player), game.GetScore(Side::Opponent)));
+    }
+
+    let path = TempPath("replay.txt");
+    game.GetReplay().SaveFile(&path).unwrap();
+    let steps = Replay::LoadFile(&path).unwrap().Steps().unwrap();
+    assert_eq!(steps.len(), live.len());
+
+    for (step, (board, player_score, opponent_score)) in steps.iter().zip(live.iter()) {
+
+        assert_eq!(&BoardText(step), board);
+        assert_eq!(step.GetScore(Side::Player), *player_score);
+        assert_eq!(step.GetScore(Side::Opponent), *opponent_score);
+    }
+
+    let last = steps.last().unwrap();
+    assert!(last.IsOver());
+    assert_eq!(last.GetWinner(), game.GetWinner());
+}
+
+#[test]
+fn ReplayStepsStayWithinTheReplay() {
+
+    let count = 5;
+    assert_eq!(ReplayStep::Next.Move(0, count), 1);
+    assert_eq!(ReplayStep::Next.Move(4, count), 4);
+    assert_eq!(ReplayStep::Back.Move(3, count), 2);
+    assert_eq!(ReplayStep::Back.Move(0, count), 0);
+    assert_eq!(ReplayStep::First.Move(3, count), 0);
+    assert_eq!(ReplayStep::Last.Move(1, count), 4);
+
+    assert_eq!(ReplayStep::FromName("back"), Some(ReplayStep::Back));
+    assert_eq!(ReplayStep::FromName("forward"), None);
+}
+
+#[test]
+fn ReplayOfAnotherVersionIsAnError() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(22));
+    PlayTurn(&mut game, &[("C", None)]);
+
+    // An action that cannot be applied to the recorded deal, since it is not the main phase.
+    let mut replay = game.GetReplay();
+    replay.actions.push(Action::Play { element: String::from("C"), tile: (5, 5), oxidation: None, order: None });
+    assert!(replay.Steps().is_err());
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Undo
+//-----------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn UndoThenRedoRestoresTheGame() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("redo-deck.txt"), Chance::Seeded(31));
+    let mut history = UndoHistory::New(UndoRule::Always);
+    for _ in 0..3 {
+
+        history.Record(game.clone());
+        PlayTurn(&mut game, &[("C", None)]);
+    }
+
+    let after = SaveText(&game, "redo-after.txt");
+    for _ in 0..3 {
+
+        history.Undo(&mut game).unwrap();
+    }
+
+    assert_eq!(history.Undo(&mut game), Err(UndoError::NothingToUndo));
+    for _ in 0..3 {
+
+        history.Redo(&mut game).unwrap();
+    }
+
+    assert_eq!(history.Redo(&mut game), Err(UndoError::NothingToRedo));
+    assert_eq!(SaveText(&game, "redo-restored.txt"), after);
+}
+
+#[test]
+fn UndoKeepsTheNextDraw() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(32));
+    let mut history = UndoHistory::New(UndoRule::Always);
+    game.Apply(Action::Pass).unwrap();
+
+    // The play ends the turn, so the player draws a card.
+    let play = FirstPlay(&game);
+    history.Record(game.clone());
+    game.Apply(play.clone()).unwrap();
+    let first = SaveText(&game, "draw-first.txt");
+
+    history.Undo(&mut game).unwrap();
+    game.Apply(play).unwrap();
+    assert_eq!(SaveText(&game, "draw-second.txt"), first);
+}
+
+// HandNames Function:
+// Parameters:
+// - game: The game to look at.
+// Description: Returns the names of the cards in the hand of the side whose turn it is, in order.
+fn HandNames(game: &Game) -> Vec<String> {
+
+    let deck = game.GetDeck(game.GetCurrentSide());
+    return (0..deck.GetHandSize()).map(|index| deck.GetFromIndex(index).name).collect();
+}
+
+#[test]
+fn UndoKeepsTheNextDrawAfterADifferentPlay() {
+
+    let mut checked = 0;
+    for seed in 0..20 {
+
+        let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(seed));
+        for _ in 0..2 {
+
+            PlayTurn(&mut game, &[]);
+        }
+        game.Apply(Action::Pass).unwrap();
+
+        // The same atom played on a bond zone, which picks no color, and on an empty tile, which picks one.
+        let legal = game.LegalActions();
+        let on_state = |state: TileState| legal.iter().find(|action| match action {
+
+            Action::Play { tile, order: None, .. } => game.GetBoard().GetTileState(*tile) == state,
+            _ => false,
+        }).cloned();
+        let zone_play = match on_state(TileState::Bond) {
+
+            Some(action) => action,
+            None => continue,
+        };
+        let empty_play = match zone_play.clone() {
+
+            Action::Play { element, oxidation, .. } => Action::Play { element, tile: game.GetBoard().FindEmptyZone().unwrap(), oxidation, order: None },
+            _ => unreachable!(),
+        };
+
+        // Both plays end the turn, so the other side draws a card.
+        let mut history = UndoHistory::New(UndoRule::Always);
+        history.Record(game.clone());
+        game.Apply(zone_play).unwrap();
+        let drawn = HandNames(&game);
+
+        history.Undo(&mut game).unwrap();
+        game.Apply(empty_play).unwrap();
+        assert_eq!(HandNames(&game), drawn, "seed {}", seed);
+        checked += 1;
+    }
+
+    assert!(checked > 10);
+}
+
+#[test]
+fn TurnRuleStopsAtTheStartOfTheTurn() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(33));
+    let mut history = UndoHistory::New(UndoRule::Turn);
+
+    // Actions within the turn can be undone.
+    history.Record(game.clone());
+    game.Apply(Action::Pass).unwrap();
+    history.Undo(&mut game).unwrap();
+    assert_eq!(game.GetPhase(), Phase::Ability);
+
+    history.Record(game.clone());
+    game.Apply(Action::Pass).unwrap();
+    let play = FirstPlay(&game);
+    history.Record(game.clone());
+    game.Apply(play).unwrap();
+
+    // The play ended the turn, so nothing before it can be undone.
+    assert_eq!(game.GetCurrentSide(), Side::Opponent);
+    assert_eq!(history.Undo(&mut game), Err(UndoError::PreviousTurn));
+    assert_eq!(game.GetCurrentSide(), Side::Opponent);
+}
+
+#[test]
+fn OffRuleRefusesUndo() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(34));
+    let mut history = UndoHistory::New(UndoRule::Off);
+    history.Record(game.clone());
+    game.Apply(Action::Pass).unwrap();
+
+    assert_eq!(history.Undo(&mut game), Err(UndoError::Disabled));
+    assert_eq!(history.Redo(&mut game), Err(UndoError::Disabled));
+    assert_eq!(game.GetPhase(), Phase::Main);
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Config files
+//-----------------------------------------------------------------------------------------------------------------------------
+
+// ConfigError Function:
+// Parameters:
+// - name: Name of the temporary config file.
+// - text: Text of the config file.
+// Description: Reads a config file that should not be allowed.
+// Return: Line and message of the error.
+fn ConfigError(name: &str, text: &str) -> (usize, String) {
+
+    match GameConfig::LoadFile(&WriteTemp(name, text)) {
+
+        Err(SaveError::Format { line, message }) => return (line, message),
+        other => panic!("Expected a format error but got {:?}.", other),
+    }
+}
+
+#[test]
+fn ConfigFileChangesSettings() {
+
+    let deck = WriteTemp("config-valid-deck.txt", "Fe 2\nO 4\n");
+    let text = format!("# A short game.\npreset lunch\n\nturns 12\nscoring polarity\ntransition_metals on\nplayer_deck {}\n", deck);
+    let config = GameConfig::LoadFile(&WriteTemp("config-valid.txt", &text)).unwrap();
+
+    // Settings after the preset replace the preset's values.
+    let mut expected = GameConfig::FromPreset("lunch").unwrap();
+    expected.Set("turns", "12").unwrap();
+    expected.Set("scoring", "polarity").unwrap();
+    expected.Set("transition_metals", "on").unwrap();
+    expected.Set("player_deck", &deck).unwrap();
+
+    assert_eq!(config, expected);
+    assert_eq!(config.turns, 12);
+    assert_eq!(config.board_size, (5, 5));
+    assert_ne!(config.player_deck, config.opponent_deck);
+}
+
+#[test]
+fn ConfigFileErrorsNameTheLine() {
+
+    assert_eq!(ConfigError("config-unknown.txt", "turns 12\ncolour blue\n"), (2, String::from("Unknown setting \"colour\".")));
+    assert_eq!(ConfigError("config-value.txt", "turns twelve\n"), (1, String::from("\"twelve\" is not a valid value for turns.")));
+    assert_eq!(ConfigError("config-range.txt", "# Too long.\nturns 60\n"), (2, String::from("turns must be from 2 to 40.")));
+    assert_eq!(ConfigError("config-odd.txt", "turns 15\n"), (1, String::from("turns must be even, so that both sides get the same number of turns.")));
+    assert_eq!(ConfigError("config-missing.txt", "turns\n"), (1, String::from("Expected a setting followed by one value.")));
+    assert_eq!(ConfigError("config-preset.txt", "preset brunch\n"), (1, String::from("Presets are standard, lunch, and tournament.")));
+    assert_eq!(ConfigError("config-metals.txt", "transition_metals maybe\n"), (1, String::from("transition_metals must be on or off.")));
+}
+
+#[test]
+fn ConfigFileChecksDeckLists() {
+
+    // Transition metals need the setting to be turned on first.
+    let metals = WriteTemp("config-metal-deck.txt", "Fe 2\nO 4\n");
+    assert_eq!(
+        ConfigError("config-metal.txt", &format!("player_deck {}\n", metals)),
+        (1, String::from("Deck lists can only hold transition metals when transition_metals is on."))
+    );
+
+    // Errors within the deck list name both the deck list line and the deck list file.
+    let broken = WriteTemp("config-broken-deck.txt", "H 4\nXx 2\n");
+    assert_eq!(
+        ConfigError("config-broken.txt", &format!("opponent_deck {}\n", broken)),
+        (1, format!("Line 2 of \"{}\": \"Xx\" is not an atom that can be put into a deck.", broken))
+    );
+
+    let (line, message) = ConfigError("config-no-deck.txt", &format!("turns 12\nplayer_deck {}\n", TempPath("no-deck.txt")));
+    assert_eq!(line, 2);
+    assert!(message.starts_with("Could not read"));
+}
+
+#[test]
+fn MissingConfigFileIsAnError() {
+
+    assert!(matches!(GameConfig::LoadFile(&TempPath("no-config.txt")), Err(SaveError::Io(_))));
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Actions
+//-----------------------------------------------------------------------------------------------------------------------------
+
+// Refused Function:
+// Parameters:
+// - game: The game to apply the action to.
+// - action: An action that should not be allowed.
+// Description: Checks that Validate and Apply give the same error and that Apply leaves the game as it was.
+// Return: The error.
+fn Refused(game: &mut Game, action: Action) -> ActionError {
+
+    let before = game.SaveText();
+    let error = game.Validate(&action).unwrap_err();
+    assert_eq!(game.Apply(action), Err(error.clone()));
+    assert_eq!(game.SaveText(), before);
+    return error;
+}
+
+#[test]
+fn ValidateRefusesActionsThatCannotBeUsed() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", MixedConfig("refuse-deck.txt"), Chance::Seeded(11));
+    let play = Action::Play { element: String::from("Fe"), tile: (0, 0), oxidation: Some(3), order: None };
+
+    // Plays wait for the main phase, and abilities and passing belong to the ability phase.
+    assert_eq!(Refused(&mut game, play.clone()), ActionError::WrongPhase);
+    assert_eq!(Refused(&mut game, Action::Evolve { element: String::from("Ne") }), ActionError::CardNotInHand);
+    game.Apply(Action::Pass).unwrap();
+    assert_eq!(Refused(&mut game, Action::Pass), ActionError::WrongPhase);
+
+    // Iron is in hand but has no oxidation state of four.
+    let iron_four = Action::Play { element: String::from("Fe"), tile: (0, 0), oxidation: Some(4), order: None };
+    assert_eq!(Refused(&mut game, iron_four), ActionError::InvalidOxidation);
+
+    let neon = Action::Play { element: String::from("Ne"), tile: (0, 0), oxidation: None, order: None };
+    assert_eq!(Refused(&mut game, neon), ActionError::CardNotInHand);
+
+    let off_board = Action::Play { element: String::from("Fe"), tile: (9, 9), oxidation: Some(3), order: None };
+    assert!(matches!(Refused(&mut game, off_board), ActionError::Board(_)));
+
+    game.Validate(&play).unwrap();
+    game.Apply(play).unwrap();
+}
+
+// WalkGame Function:
+// Parameters:
+// - game: The game to play until it is over.
+// - check: Called with the game before every action.
+// Description: Plays a whole game, picking a different legal action each step so that abilities are used as well as
+//   plays.
+fn WalkGame(game: &mut Game, mut check: impl FnMut(&Game)) {
+
+    let mut step: usize = 0;
+    while !game.IsOver() {
+
+        check(game);
+
+        let legal = game.LegalActions();
+        game.Apply(legal[(step * 7) % legal.len()].clone()).unwrap();
+        step += 1;
+    }
+}
+
+#[test]
+fn EveryLegalActionCanBeApplied() {
+
+    let games = [
+        Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(51)),
+        Game::NewWithChance("Ana", "Ben", GameConfig::FromPreset("lunch").unwrap(), Chance::Seeded(52)),
+        Game::NewWithChance("Ana", "Ben", MixedConfig("legal-deck.txt"), Chance::Seeded(53)),
+    ];
+
+    for mut game in games {
+
+        let mut listed_ability = false;
+        WalkGame(&mut game, |game| {
+
+            let legal = game.LegalActions();
+            assert!(!legal.is_empty());
+
+            for action in legal {
+
+                game.Validate(&action).unwrap();
+                game.clone().Apply(action.clone()).unwrap();
+                listed_ability |= !matches!(action, Action::Play { .. } | Action::Pass);
+            }
+        });
+
+        // Abilities were checked as well as plays, and nothing is legal once the game is over.
+        assert!(listed_ability);
+        assert!(game.LegalActions().is_empty());
+        assert_eq!(Refused(&mut game, Action::Pass), ActionError::GameOver);
+    }
+}
+
+#[test]
+fn PreviewMatchesThePlay() {
+
+    let games = [
+        Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(61)),
+        Game::NewWithChance("Ana", "Ben", MixedConfig("preview-deck.txt"), Chance::Seeded(62)),
+    ];
+
+    for mut game in games {
+
+        let mut scored = false;
+        WalkGame(&mut game, |game| {
+
+            for action in game.LegalActions() {
+
+                match action.clone() {
+
+                    Action::Play { element, tile, oxidation, order } => {
+
+                        let preview = game.PreviewPlay(&element, tile, oxidation, order).unwrap();
+                        let side = game.GetCurrentSide();
+
+                        let mut copy = game.clone();
+                        let outcome = copy.Apply(action).unwrap();
+                        assert_eq!(preview.points, outcome.points);
+                        assert_eq!(copy.GetScore(side) - game.GetScore(side), preview.points.unwrap_or(0));
+                        scored |= preview.points != None;
+                    },
+                    _ => (),
+                }
+            }
+        });
+
+        assert!(scored);
+    }
+}
+
+//-----------------------------------------------------------------------------------------------------------------------------
+// Hidden cards
+//-----------------------------------------------------------------------------------------------------------------------------
+
+// DeckText Function:
+// Parameters:
+// - game: The game to look at.
+// Description: Returns the opponent's hand and deck as they are written to a save file, so that two can be compared.
+fn DeckText(game: &Game) -> String {
+
+    let mut writer = SaveWriter::New("deck");
+    game.opponent.deck.Save(&mut writer);
+    return writer.Finish();
+}
+
+// PlayEvolvedCard Function:
+// Parameters:
+// - game: The game to play.
+// Description: Plays until the opponent has evolved a card and played it. Every other choice is the first legal one.
+// Return: Name of the card the evolved atom was dealt as.
+fn PlayEvolvedCard(game: &mut Game) -> String {
+
+    let mut evolved: Option<Card> = None;
+    while !game.IsOver() {
+
+        let legal = game.LegalActions();
+        let mut action = legal[0].clone();
+        if game.GetCurrentSide() == Side::Opponent {
+
+            for option in legal.iter() {
+
+                match (option, &evolved) {
+
+                    (Action::Evolve { element }, None) => {
+
+                        let mut card = Card::FromName(element).unwrap();
+                        card.Evolve();
+                        evolved = Some(card);
+                        action = option.clone();
+                        break;
+                    },
+                    (Action::Play { element, order: None, .. }, Some(card)) if *element == card.name => {
+
+                        game.Apply(option.clone()).unwrap();
+                        return card.origin.clone();
+                    },
+                    _ => (),
+                }
+            }
+        }
+
+        game.Apply(action).unwrap();
+    }
+
+    panic!("Expected the opponent to play an evolved card.");
+}
+
+#[test]
+fn DeterminizeOnlyUsesSeenCards() {
+
+    let mut game = Game::NewWithChance("Ana", "Ben", GameConfig::default(), Chance::Seeded(17));
+    let origin = PlayEvolvedCard(&mut game);
+
+    // The evolved atom is counted as the card it was dealt as.
+    let list = game.config.opponent_deck.clone();
+    let played = game.opponent.played.clone();
+    assert_eq!(played.last(), Some(&origin));
+
+    let count = |cards: &[Card], name: &str| cards.iter().filter(|card| card.name == name).count();
+    let unseen = game.GetUnseenCards(Side::Player);
+    let played_count = played.iter().filter(|name| **name == origin).count();
+    assert_eq!(count(&unseen, &origin), count(&list.GetCards(), &origin) - played_count);
+
+    // A copy whose opponent really holds different cards.
+    let mut other = game.clone();
+    other.opponent.deck.Resample(&list, &[], &mut Chance::Seeded(99));
+    assert_ne!(DeckText(&other), DeckText(&game));
+
+    // Both guesses are the same, so the real cards cannot leak into them.
+    let hand_size = game.opponent.deck.GetHandSize();
+    game.Determinize(Side::Player, 3);
+    other.Determinize(Side::Player, 3);
+    assert_eq!(DeckText(&game), DeckText(&other));
+
+    // The guessed hand is made from cards the player has not seen.
+    let deck = &game.opponent.deck;
+    let hand: Vec<Card> = (0..deck.GetHandSize()).map(|index| deck.GetFromIndex(index)).collect();
+    assert_eq!(hand.len(), hand_size);
+    for card in hand.iter() {
+
+        assert!(count(&hand, &card.name) <= count(&unseen, &card.name), "{} was already played", card.name);
+    }
+}
@@ -0,0 +1,164 @@
+// Std modules.
+use std::fmt;
+use std::mem;
+
+// My modules.
+use super::Game;
+
+// Enum for when a person is allowed to undo.
+// - Off: Undo and redo cannot be used. Meant for ranked games.
+// - Turn: Only actions from the current turn can be undone.
+// - Always: Any action can be undone. Undoing a play also takes back everything the other side did after it, so
+//   this is only meant for games against the computer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UndoRule {
+    Off,
+    Turn,
+    Always,
+}
+
+impl UndoRule {
+
+    // FromName Function:
+    // Parameters:
+    // - name: Name of the rule typed by the user.
+    // Description: Returns the rule with the given name. None if the name is not recognised.
+    pub fn FromName(name: &str) -> Option<UndoRule> {
+
+        match name {
+
+            "off" => return Some(UndoRule::Off),
+            "turn" => return Some(UndoRule::Turn),
+            "always" => return Some(UndoRule::Always),
+            _ => return None,
+        }
+    }
+}
+
+// Enum describing why undo or redo could not be used.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UndoError {
+    Disabled,
+    NothingToUndo,
+    NothingToRedo,
+    PreviousTurn,
+}
+
+// The error messages shown within the action log.
+impl fmt::Display for UndoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        match self {
+
+            UndoError::Disabled => return write!(f, "Error: Undo is turned off for this game."),
+            UndoError::NothingToUndo => return write!(f, "Error: There is nothing to undo."),
+            UndoError::NothingToRedo => return write!(f, "Error: There is nothing to redo."),
+            UndoError::PreviousTurn => return write!(f, "Error: Only actions from this turn can be undone."),
+        }
+    }
+}
+
+// Struct holding copies of the game from before each action that a person applied, and copies of the game from
+// before each undo so that they can be redone.
+pub struct UndoHistory {
+
+    rule: UndoRule,
+    undo: Vec<Game>,
+    redo: Vec<Game>
+}
+
+impl UndoHistory {
+
+    // New Function:
+    // Parameters:
+    // - rule: When undo is allowed.
+    // Description: Creates an empty history.
+    pub fn New(rule: UndoRule) -> UndoHistory {
+
+        return UndoHistory {
+
+            rule,
+            undo: Vec::new(),
+            redo: Vec::new()
+        };
+    }
+
+    // Record Function:
+    // Parameters:
+    // - before: Copy of the game from before an action was applied.
+    // Description: Remembers the game so the action can be undone. Anything that could be redone is forgotten,
+    //   since the game has moved on from it.
+    pub fn Record(&mut self, before: Game) {
+
+        if self.rule == UndoRule::Off {
+
+            return;
+        }
+
+        self.undo.push(before);
+        self.redo.clear();
+    }
+
+    // Clear Function:
+    // Description: Forgets everything. Used when a different game is started or loaded.
+    pub fn Clear(&mut self) {
+
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    // Undo Function:
+    // Parameters:
+    // - game: The game to take the last action back from.
+    // Description: Puts the game back to how it was before the last recorded action. Random outcomes that were
+    //   picked after that point are picked again in the same order. Draws and compound colors are given back
+    //   separately, so undo cannot be used to draw different cards even when a different play is made.
+    pub fn Undo(&mut self, game: &mut Game) -> Result<(), UndoError> {
+
+        if self.rule == UndoRule::Off {
+
+            return Err(UndoError::Disabled);
+        }
+
+        match self.undo.last() {
+
+            Some(before) => {
+
+                let same_turn = (before.turn_number == game.turn_number) && (before.current_side == game.current_side);
+                if (self.rule == UndoRule::Turn) && !same_turn {
+
+                    return Err(UndoError::PreviousTurn);
+                }
+            },
+            None => return Err(UndoError::NothingToUndo),
+        }
+
+        let mut before = self.undo.pop().unwrap();
+        before.chance.Rewind(&game.chance);
+
+        self.redo.push(mem::replace(game, before));
+        return Ok(());
+    }
+
+    // Redo Function:
+    // Parameters:
+    // - game: The game to put the undone action back into.
+    // Description: Puts the game back to how it was before the last undo.
+    pub fn Redo(&mut self, game: &mut Game) -> Result<(), UndoError> {
+
+        if self.rule == UndoRule::Off {
+
+            return Err(UndoError::Disabled);
+        }
+
+        match self.redo.pop() {
+
+            Some(after) => {
+
+                self.undo.push(mem::replace(game, after));
+                return Ok(());
+            },
+            None => return Err(UndoError::NothingToRedo),
+        }
+    }
+}
@@ -1,14 +1,43 @@
+// My modules.
+use super::PlayedAtom;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
 // Enum used to check the state of a tile.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum TileState {
     Empty,
     Restricted,
     Bond,
     Atom,
     Parent,
+    Inert,
+}
+
+// Tile states are saved by name.
+impl SaveField for TileState {
+
+    fn ToField(&self) -> String {
+
+        return format!("{:?}", self);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        match field {
+
+            "Empty" => return Some(TileState::Empty),
+            "Restricted" => return Some(TileState::Restricted),
+            "Bond" => return Some(TileState::Bond),
+            "Atom" => return Some(TileState::Atom),
+            "Parent" => return Some(TileState::Parent),
+            "Inert" => return Some(TileState::Inert),
+            _ => return None,
+        }
+    }
 }
 
 // Board tile data members. Members within an option do not exist within all tile states.
+#[derive(Clone)]
 pub struct BoardTile {
 
     // Universal data members. A tile contains these regardless of state.
@@ -24,14 +53,13 @@ pub struct BoardTile {
 
     // Atom zone data members. Unique to atom zones and parent zones.
     parent: Option<(usize, usize)>,
-    bond_number: Option<u16>,
+    bond_capacity: Option<u16>,
     is_metal: Option<bool>,
+    is_ion: Option<bool>,
+    electronegativity: Option<u16>,
     presedence: Option<u8>,
 
-    // Parent atom data members. Unique to parent zones.
-    number_of_atoms: Option<u8>,
-    number_of_neutral: Option<u8>,
-    largest_bond: Option<u16>,
+    // Parent atom data members. Unique to parent zones. The bonds of the compound are kept by the board's bond graph.
     atomic_sum: Option<u16>,
     metal_component: Option<String>
 }
@@ -51,12 +79,11 @@ impl Default for BoardTile {
             restrict_counter: None,
             bond_tile: None,
             parent: None,
-            bond_number: None,
+            bond_capacity: None,
             is_metal: None,
+            is_ion: None,
+            electronegativity: None,
             presedence: None,
-            number_of_atoms: None,
-            number_of_neutral: None,
-            largest_bond: None,
             atomic_sum: None,
             metal_component: None
         }
@@ -82,12 +109,11 @@ impl BoardTile {
         self.restrict_counter = None;
         self.bond_tile = None;
         self.parent = None;
-        self.bond_number = None;
+        self.bond_capacity = None;
         self.is_metal = None;
+        self.is_ion = None;
+        self.electronegativity = None;
         self.presedence = None;
-        self.number_of_atoms = None;
-        self.number_of_neutral = None;
-        self.largest_bond = None;
         self.atomic_sum = None;
         self.metal_component = None;
     }
@@ -121,43 +147,54 @@ impl BoardTile {
         self.bond_tile = Some(bond_tile);
     }
 
+    // MakeInertZone Function:
+    // - Parameters:
+    //   - symbol: The symbol to display within the inert tile.
+    // - Description: Applies the inert state to a tile. Inert tiles hold an atom that cannot bond, so they never have
+    //   bond zones and nothing can be played on them. This function should only be used on empty tiles and bond zones.
+    pub fn MakeInertZone(&mut self, symbol: String) {
+
+        // Create an empty tile with inert state.
+        self.ClearTile();
+        self.state = TileState::Inert;
+        self.color = (128, 128, 128);
+        self.symbol = symbol;
+    }
+
     // MakeAtomZone Function:
     // - Parameters:
     //   - parent: Parent atom within the overall compound.
-    //   - symbol: The symbol to display within the atom tile.
-    //   - bond_number: The number of remaining bonds that the atom has.
-    //   - is_metal: Boolean value flagged as true if the atom is a metal.
+    //   - atom: The atom being played. Its number of bonds becomes the bonding capacity of the tile.
     //   - presedence: Turn number the atom was played on.
     // - Description: Applies the atom state to a tile. This function should be used only on bond zones.
-    pub fn MakeAtomZone(&mut self, parent: (usize, usize), symbol: String, bond_number: u16, is_metal: bool, presedence: u8) {
+    pub fn MakeAtomZone(&mut self, parent: (usize, usize), atom: &PlayedAtom, presedence: u8) {
 
         // Create an empty tile. Fill in atom related fields.
         self.ClearTile();
         self.state = TileState::Atom;
-        self.symbol = symbol;
+        self.symbol = atom.symbol.clone();
 
         // Information related to the atom in the space.
         self.parent = Some(parent);
-        self.bond_number = Some(bond_number);
-        self.is_metal = Some(is_metal);
+        self.bond_capacity = Some(atom.bond_number);
+        self.is_metal = Some(atom.is_metal);
+        self.is_ion = Some(atom.is_ion);
+        self.electronegativity = atom.electronegativity;
         self.presedence = Some(presedence);
     }
 
     // MakeParentZone Function:
     // - Parameters:
     //   - tile: The tile that the atom itself is on.
-    //   - symbol: The symbol to display within the atom tile.
-    //   - bond_number: The number of remaining bonds that the atom has.
-    //   - is_metal: Boolean value flagged as true if the atom is a metal.
-    //   - metal_component: Option containing the name of the compound's metal component or nothing at all.
-    //   - Sum: The atomic number of the atom used to initialize the atomic_sum field.
+    //   - atom: The atom being played. Its atomic number is used to initialize the atomic_sum field.
     //   - Presedence: Turn number the atom was played on.
+    //   - metal_component: Option containing the name of the compound's metal component or nothing at all.
     // - Description: Applies the parent state to a tile. This function should only be used when an atom is played on an
     //   empty tile. In this case, the atom becomes the parent of any atoms that bond to it.
-    pub fn MakeParentZone(&mut self, tile: (usize, usize), symbol: String, bond_number: u16, is_metal: bool, presedence: u8, metal_component: Option<String> , sum: u16) {
+    pub fn MakeParentZone(&mut self, tile: (usize, usize), atom: &PlayedAtom, presedence: u8, metal_component: Option<String>) {
 
         // Make the atom component of the parent atom.
-        self.MakeAtomZone(tile, symbol, bond_number, is_metal, presedence);
+        self.MakeAtomZone(tile, atom, presedence);
         self.state = TileState::Parent;
 
         // Parent data members provided as parameters.
@@ -166,12 +203,7 @@ impl BoardTile {
             Some(component) => self.metal_component = Some(component),
             None => self.metal_component = None,
         }
-        self.atomic_sum = Some(sum);
-
-        // Parent data members initialized when a parent is created.
-        self.number_of_atoms = Some(1);
-        self.number_of_neutral = Some(0);
-        self.largest_bond = Some(0);
+        self.atomic_sum = Some(atom.atomic_number);
     }
 
     //-----------------------------------------------------------------------------------------------------------------------------
@@ -220,7 +252,7 @@ impl BoardTile {
         
         match &self.bond_tile {
 
-            Some(value) => return true,
+            Some(_) => return true,
             None => return false,
         }
     }
@@ -275,7 +307,7 @@ impl BoardTile {
 
             match &self.bond_tile {
 
-                Some(value) => self.state = TileState::Bond,
+                Some(_) => self.state = TileState::Bond,
                 None => self.state = TileState::Empty,
             }
         }
@@ -297,33 +329,29 @@ impl BoardTile {
         return self.is_metal.unwrap();
     }
 
-    // BondAtom Function:
-    // - Use within: Atom or parent state.
-    // - Parameters: 
-    //   - partner_bonds: Number of available bonds that the partner atom has.
-    // - Description: Calculates the remaining number of bonds when two atoms are bonded together.
-    // - Return: True if the atom becomes neutral. Fals if the atom has remaining bonds.
-    pub fn BondAtom(&mut self, partner_bonds: u16) -> bool {
-
-        if self.bond_number.unwrap() <= partner_bonds {
-
-            // All bonds are removed from the atom.
-            self.bond_number = Some(0);
-            return true;
-        } else {
-
-            // There must be some number of remaining bonds.
-            self.bond_number = Some(self.bond_number.unwrap() - partner_bonds);
-            return false;
-        }
+    // IsIon Function:
+    // - Use Within: Atom or parent state.
+    // - Description: Returns true if the atom at the given space is a polyatomic ion. False otherwise.
+    pub fn IsIon(&self) -> bool {
+
+        return self.is_ion.unwrap();
+    }
+
+    // GetElectronegativity Function:
+    // - Use Within: Atom or parent state.
+    // - Description: Returns the electronegativity of the atom times one hundred. None for polyatomic ions.
+    pub fn GetElectronegativity(&self) -> Option<u16> {
+
+        return self.electronegativity;
     }
 
-    // GetBondNumber Function:
+    // GetBondCapacity Function:
     // - Use within: Atom or parent state.
-    // - Description: Returns the number of remaining bonds that an atom has.
-    pub fn GetBondNumber(&self) -> u16 {
+    // - Description: Returns the number of bonds the atom was played with. Bonds it has already formed are not taken
+    //   away, since those are kept by the board's bond graph.
+    pub fn GetBondCapacity(&self) -> u16 {
 
-        return self.bond_number.unwrap();
+        return self.bond_capacity.unwrap();
     }
 
     // GetParentTile Function:
@@ -357,50 +385,137 @@ impl BoardTile {
         }
     }
 
-    // UpdateCompound Function:
+    // AddToCompound Function:
     // - Use within: Parent state.
     // - Parameters:
-    //   - neutral_created: Neutral atoms created as a result of bonding.
-    //   - atomic_number: Atomic number of the atom that was added to the compound.
-    //   - bond_number: Size of the bond that occured.
+    //   - atomic_number: Atomic number of the atom that was added to the compound. Polyatomic ions add the sum of
+    //     the atomic numbers of their atoms, so they score for every atom within them.
     //   - metal: Symbol for metal is attached if the new atom is a metal.
     // - Description: Update parent data members related to the overall compound whenever a new atom is added.
-    // - Return: True if the atom is neutral. False if the atom still is not neutral.
-    pub fn UpdateCompound(&mut self, neutral_created: u8, atomic_number: u16, bonds_created: u16, metal: Option<String>) -> bool {
+    pub fn AddToCompound(&mut self, atomic_number: u16, metal: Option<String>) {
+
+        self.atomic_sum = Some(self.atomic_sum.unwrap().saturating_add(atomic_number));
 
-        // Increment values.
-        self.number_of_atoms = Some(self.number_of_atoms.unwrap() + 1);
-        self.number_of_neutral = Some(self.number_of_neutral.unwrap() + neutral_created);
-        self.atomic_sum = Some(self.atomic_sum.unwrap() + atomic_number);
-        
         // Add metal component if new atom was a metal.
         match metal {
 
             Some(value) => self.metal_component = Some(value),
             None => (), // Nothing happens in this case but Rust requires all cases to be covered.
         }
+    }
+
+    // JoinCompound Function:
+    // - Use within: Atom or parent state.
+    // - Parameters:
+    //   - parent: Parent atom of the compound being joined.
+    //   - color: Color of the compound being joined.
+    // - Description: Moves an atom into another compound when two compounds are merged. The parent atom of a
+    //   compound that is merged into another becomes a regular atom.
+    pub fn JoinCompound(&mut self, parent: (usize, usize), color: (u8, u8, u8)) {
+
+        if (self.state == TileState::Parent) && (self.parent != Some(parent)) {
+
+            self.state = TileState::Atom;
+            self.atomic_sum = None;
+            self.metal_component = None;
+        }
+
+        self.parent = Some(parent);
+        self.color = color;
+    }
+
+    // GetAtomicSum Function:
+    // - Use within: Parent state.
+    // - Description: Returns the sum of the atomic numbers of every atom within the compound.
+    pub fn GetAtomicSum(&self) -> u16 {
 
-        // If bond_number is less than the current largest bond, replace the current largest bond.
-        if bonds_created > self.largest_bond.unwrap() {
+        return self.atomic_sum.unwrap();
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Save Functions
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // Save Function:
+    // - Parameters:
+    //   - writer: The save file being written.
+    // - Description: Writes every data member of the tile as one record, including the members of other states that
+    //   restricted tiles keep.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("tile", &[
+            self.state.ToField(),
+            self.color.ToField(),
+            self.symbol.ToField(),
+            self.restrict_counter.ToField(),
+            self.bond_tile.ToField(),
+            self.parent.ToField(),
+            self.bond_capacity.ToField(),
+            self.is_metal.ToField(),
+            self.is_ion.ToField(),
+            self.electronegativity.ToField(),
+            self.presedence.ToField(),
+            self.atomic_sum.ToField(),
+            self.metal_component.ToField()
+        ]);
+    }
 
-            self.largest_bond = Some(bonds_created);
+    // Load Function:
+    // - Parameters:
+    //   - reader: The save file being read.
+    //   - size: Width and height of the board the tile is on.
+    // - Description: Reads a tile written by Save.
+    // - Return: The tile. Error if a coordinate is off the board or the tile is missing a member its state needs.
+    pub fn Load(reader: &mut SaveReader, size: (usize, usize)) -> Result<BoardTile, SaveError> {
+
+        let record = reader.Read("tile")?;
+
+        let tile = BoardTile {
+
+            state: record.Get(0)?,
+            color: record.Get(1)?,
+            symbol: record.Get(2)?,
+            restrict_counter: record.Get(3)?,
+            bond_tile: record.Get(4)?,
+            parent: record.Get(5)?,
+            bond_capacity: record.Get(6)?,
+            is_metal: record.Get(7)?,
+            is_ion: record.Get(8)?,
+            electronegativity: record.Get(9)?,
+            presedence: record.Get(10)?,
+            atomic_sum: record.Get(11)?,
+            metal_component: record.Get(12)?
+        };
+
+        let on_board = |coords: Option<(usize, usize)>| coords.is_none_or(|(x, y)| (x < size.0) && (y < size.1));
+        if !on_board(tile.bond_tile) || !on_board(tile.parent) {
+
+            return Err(record.Error("Tile points to a tile that is not on the board."));
         }
 
-        // Trur is returned if all atoms within the compound are neutral.
-        if self.number_of_atoms == self.number_of_neutral {
+        if !tile.HasStateMembers() {
 
-            return true;
+            return Err(record.Error(&format!("Tile is missing a field needed by the {:?} state.", tile.state)));
         }
 
-        return false;
+        return Ok(tile);
     }
 
-    // GetCompoundScore Function:
-    // - Use within: Parent state.
-    // - Description: Returns the score of a compound. Since only neutral atoms score, it is recomended to use this
-    //   function only on neutral atoms.
-    pub fn GetCompoundScore(&self) -> u16 {
+    // HasStateMembers Function:
+    // - Use within: All states.
+    // - Description: Returns true if the tile holds every data member that the getters of its state unwrap.
+    fn HasStateMembers(&self) -> bool {
+
+        let atom = self.parent.is_some() && self.bond_capacity.is_some() && self.is_metal.is_some()
+            && self.is_ion.is_some() && self.presedence.is_some();
 
-        return self.atomic_sum.unwrap() * self.largest_bond.unwrap();
+        match self.state {
+
+            TileState::Empty | TileState::Inert => return true,
+            TileState::Restricted => return self.restrict_counter.is_some_and(|counter| counter > 0),
+            TileState::Bond => return self.bond_tile.is_some(),
+            TileState::Atom => return atom,
+            TileState::Parent => return atom && self.atomic_sum.is_some(),
+        }
     }
-}
\ No newline at end of file
+}
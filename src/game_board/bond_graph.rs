@@ -0,0 +1,209 @@
+// My modules.
+use super::scoring::BondType;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
+// Largest number of electron pairs two atoms can share. Atoms with more bonds left over keep them for other atoms.
+pub const MAX_BOND_ORDER: u16 = 3;
+
+// Struct holding one bond between the atoms on two tiles.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BondEdge {
+
+    // Tiles of the two atoms. The first atom owned the bond zone that the second atom was played on.
+    pub first: (usize, usize),
+    pub second: (usize, usize),
+
+    // Number of electron pairs shared. One for a single bond, two for a double bond, and three for a triple bond.
+    pub order: u16,
+
+    pub bond_type: BondType
+}
+
+impl BondEdge {
+
+    // Touches Function:
+    // Parameters:
+    // - tile: Coordinates of a tile.
+    // Description: Returns true if the atom on the tile is one of the two atoms of the bond.
+    pub fn Touches(&self, tile: (usize, usize)) -> bool {
+
+        return (self.first == tile) || (self.second == tile);
+    }
+
+    // GetOther Function:
+    // Parameters:
+    // - tile: Coordinates of one atom of the bond.
+    // Description: Returns the coordinates of the other atom of the bond.
+    pub fn GetOther(&self, tile: (usize, usize)) -> (usize, usize) {
+
+        if self.first == tile {
+
+            return self.second;
+        }
+
+        return self.first;
+    }
+
+    // GetOrderName Function:
+    // Description: Returns the name of the bond order shown within the action log.
+    pub fn GetOrderName(&self) -> &'static str {
+
+        match self.order {
+
+            1 => return "single",
+            2 => return "double",
+            _ => return "triple",
+        }
+    }
+}
+
+/*
+Bond Graph:
+- Description: Every bond on the board is an edge between the tiles of its two atoms. The number of bonds an atom
+  has left is its bonding capacity minus the orders of its edges, and a compound is every atom that can be reached by
+  following edges.
+*/
+#[derive(Clone, Default)]
+pub struct BondGraph {
+
+    edges: Vec<BondEdge>
+}
+
+impl BondGraph {
+
+    // AddBond Function:
+    // Parameters:
+    // - edge: The bond to add.
+    // Description: Adds a bond between two atoms.
+    pub fn AddBond(&mut self, edge: BondEdge) {
+
+        self.edges.push(edge);
+    }
+
+    // GetBond Function:
+    // Parameters:
+    // - a: Coordinates of one atom.
+    // - b: Coordinates of the other atom.
+    // Description: Finds the bond between two atoms.
+    // Return: None if the atoms are not bonded.
+    pub fn GetBond(&self, a: (usize, usize), b: (usize, usize)) -> Option<BondEdge> {
+
+        return self.edges.iter().find(|edge| edge.Touches(a) && edge.Touches(b)).copied();
+    }
+
+    // GetBonds Function:
+    // Parameters:
+    // - tile: Coordinates of an atom.
+    // Description: Returns every bond of an atom, in the order they were formed.
+    pub fn GetBonds(&self, tile: (usize, usize)) -> Vec<BondEdge> {
+
+        return self.edges.iter().filter(|edge| edge.Touches(tile)).copied().collect();
+    }
+
+    // CountBonds Function:
+    // Parameters:
+    // - tile: Coordinates of an atom.
+    // Description: Returns the number of bonds an atom has used, counting a double bond as two.
+    pub fn CountBonds(&self, tile: (usize, usize)) -> u16 {
+
+        return self.edges.iter().filter(|edge| edge.Touches(tile)).map(|edge| edge.order).sum();
+    }
+
+    // GetConnected Function:
+    // Parameters:
+    // - tile: Coordinates of an atom.
+    // Description: Follows bonds outward from an atom to find every atom within its compound.
+    // Return: Coordinates of every atom within the compound, starting with the given atom.
+    pub fn GetConnected(&self, tile: (usize, usize)) -> Vec<(usize, usize)> {
+
+        let mut connected: Vec<(usize, usize)> = vec![tile];
+        let mut index = 0;
+
+        while index < connected.len() {
+
+            let current = connected[index];
+            for edge in self.edges.iter().filter(|edge| edge.Touches(current)) {
+
+                let other = edge.GetOther(current);
+                if !connected.contains(&other) {
+
+                    connected.push(other);
+                }
+            }
+
+            index += 1;
+        }
+
+        return connected;
+    }
+
+    // GetCompoundBonds Function:
+    // Parameters:
+    // - tile: Coordinates of any atom within a compound.
+    // Description: Returns every bond within the compound of an atom.
+    pub fn GetCompoundBonds(&self, tile: (usize, usize)) -> Vec<BondEdge> {
+
+        let connected = self.GetConnected(tile);
+        return self.edges.iter().filter(|edge| connected.contains(&edge.first)).copied().collect();
+    }
+
+    // RemoveTile Function:
+    // Parameters:
+    // - tile: Coordinates of an atom that is being removed from the board.
+    // Description: Removes every bond of an atom.
+    pub fn RemoveTile(&mut self, tile: (usize, usize)) {
+
+        self.edges.retain(|edge| !edge.Touches(tile));
+    }
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the number of bonds followed by each bond as one record.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("bonds", &[self.edges.len().ToField()]);
+        for edge in self.edges.iter() {
+
+            writer.Write("bond", &[edge.first.ToField(), edge.second.ToField(), edge.order.ToField(), edge.bond_type.ToField()]);
+        }
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // - is_atom: Returns true if the tile at the given coordinates is on the board and holds an atom.
+    // Description: Reads a bond graph written by Save.
+    // Return: The bond graph. Error if a bond order is not allowed or a bond is not between two atoms.
+    pub fn Load(reader: &mut SaveReader, is_atom: impl Fn((usize, usize)) -> bool) -> Result<BondGraph, SaveError> {
+
+        let mut graph = BondGraph::default();
+
+        let length: usize = reader.Read("bonds")?.Get(0)?;
+        for _ in 0..length {
+
+            let record = reader.Read("bond")?;
+            let edge = BondEdge {
+
+                first: record.Get(0)?,
+                second: record.Get(1)?,
+                order: record.Get(2)?,
+                bond_type: record.Get(3)?
+            };
+
+            if !(1..=MAX_BOND_ORDER).contains(&edge.order) {
+
+                return Err(record.Error("Bond order is not allowed."));
+            }
+
+            if (edge.first == edge.second) || !is_atom(edge.first) || !is_atom(edge.second) {
+
+                return Err(record.Error("Bond is not between two atoms on the board."));
+            }
+
+            graph.AddBond(edge);
+        }
+
+        return Ok(graph);
+    }
+}
@@ -1,6 +1,8 @@
-extern crate rand;
-use rand::Rng;
+// My modules.
+use crate::chance::{Chance, Stream};
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
 
+#[derive(Clone)]
 pub struct ColorPicker {
 
     colors: Vec<(u8, u8, u8)>,
@@ -27,21 +29,48 @@ impl Default for ColorPicker {
 impl ColorPicker {
 
     // GetColor Function:
+    // Parameters:
+    // - chance: Source of the random pick.
     // Description: Returns a unique color from ColorPicker's list of colors and removes taht color form the options.
+    //   Large boards can hold more compounds than there are colors, so the colors are reused once every one is taken.
     // Return: Tuple representing rgb value.
-    pub fn GetColor(&mut self) -> (u8, u8, u8) {
+    pub fn GetColor(&mut self, chance: &mut Chance) -> (u8, u8, u8) {
 
-        // Initialize random number generator.
-        let mut random = rand::thread_rng();
+        if self.size == 0 {
+
+            *self = ColorPicker::default();
+        }
 
         // Generate a random index and use it to choose a color.
-        let index: usize = random.gen_range(0..self.size);
+        let index: usize = chance.Pick(Stream::Color, self.size);
         let ret_val = self.colors[index];
 
         // Remove the chosen color from the struct.
         self.colors.remove(index);
-        self.size = self.size - 1;
+        self.size -= 1;
 
         return ret_val;
     }
-}
\ No newline at end of file
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the colors that have not been used yet.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        let colors: Vec<String> = self.colors.iter().map(|color| color.ToField()).collect();
+        writer.Write("colors", &colors);
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads the colors written by Save.
+    pub fn Load(reader: &mut SaveReader) -> Result<ColorPicker, SaveError> {
+
+        let colors: Vec<(u8, u8, u8)> = reader.Read("colors")?.GetAll(0)?;
+        let size = colors.len();
+
+        return Ok(ColorPicker { colors, size });
+    }
+}
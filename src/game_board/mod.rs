@@ -3,248 +3,652 @@ extern crate termion;
 use termion::color::{Fg, Rgb};
 
 // Std modules.
-use core::array::from_fn;
+use std::fmt;
 
 // My modules.
 mod board_tile;
-use board_tile::{BoardTile, TileState};
+use board_tile::BoardTile;
+pub use board_tile::TileState;
 mod color_picker;
 use color_picker::ColorPicker;
+mod scoring;
+pub use scoring::{BondType, ScoringMode};
+mod bond_graph;
+use bond_graph::BondGraph;
+pub use bond_graph::{BondEdge, MAX_BOND_ORDER};
+#[cfg(test)]
+mod tests;
+use crate::chance::Chance;
+use crate::save_file::{SaveField, SaveWriter, SaveReader, SaveError};
+
+// Width and height of the board when none is chosen.
+pub const DEFAULT_BOARD_SIZE: (usize, usize) = (6, 6);
+
+// Smallest and largest number of columns or rows. Columns are named with letters, and the computer has to look at
+// every pair of tiles when it restricts, so boards are kept small.
+pub const MIN_BOARD_SIZE: usize = 3;
+pub const MAX_BOARD_SIZE: usize = 12;
+
+// Enum describing why an operation on the board failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoardError {
+    OutOfBounds,
+    Restricted,
+    Occupied,
+    MetalsCannotBond,
+    MixedMetals,
+    RestrictOnAtom,
+    AlreadyRestricted,
+    SameZone,
+    NoCompound,
+    IonicOnly,
+    InvalidBondOrder,
+}
 
-// Struct to return values easily.
-pub struct BoardStatus {
-    
-    pub error_message: Option<String>,
-    pub points: Option<u16>
+// The error messages shown within the action log.
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        let message = match self {
+
+            BoardError::OutOfBounds => "Error: Zone is not on the board.",
+            BoardError::Restricted => "Error: Selected space is Restricted.",
+            BoardError::Occupied => "Error: Selected space is occupied.",
+            BoardError::MetalsCannotBond => "Error: Metals cannot bond with each other.",
+            BoardError::MixedMetals => "Error: Compounds can only contain one type of metal, with one oxidation state.",
+            BoardError::RestrictOnAtom => "Error: Cannot restrict on top of atoms.",
+            BoardError::AlreadyRestricted => "Error: Zone is already restricted.",
+            BoardError::SameZone => "Error: Restrict requires two different zones.",
+            BoardError::NoCompound => "Error: No compound specified.",
+            BoardError::IonicOnly => "Error: Ions can only bond between a metal and a non-metal.",
+            BoardError::InvalidBondOrder => "Error: Bond order must be from one to the bonds both atoms have left.",
+        };
+
+        return write!(f, "{}", message);
+    }
 }
 
+// Struct describing what would happen if an atom was played. Returned by SimulateBond.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BondPreview {
+
+    // True if the atom would start a new compound on an empty tile.
+    pub new_compound: bool,
+
+    // Size of the bond that would be formed with the atom that owns the bond zone.
+    pub bonds_formed: u16,
+
+    // Sum of the sizes of the bonds that would be formed with other neighboring atoms.
+    pub extra_bonds: u16,
+
+    // Bonds that the played atom would have left after bonding.
+    pub remaining_bonds: u16,
+
+    // Score of the compound if the atom would complete it. None if the compound would not be neutral.
+    pub points: Option<u16>,
+
+    // Type of the bond that would be formed. None if no bond would be formed.
+    pub bond_type: Option<BondType>
+}
+
+// Struct describing an atom being played onto the board. Transition metals use the oxidation state chosen by the
+// player as their number of bonds.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PlayedAtom {
+
+    // String which is displayed inside of a board tile.
+    pub symbol: String,
+
+    // Number of bonds that the atom can form. Atoms that cannot bond are inert.
+    pub bond_number: u16,
+
+    // True for metals, and for positive polyatomic ions which bond the same way metals do.
+    pub is_metal: bool,
+
+    // True for polyatomic ions, which only form ionic bonds.
+    pub is_ion: bool,
+
+    // Electronegativity of the atom times one hundred. None for polyatomic ions.
+    pub electronegativity: Option<u16>,
+
+    // Atomic number of the atom from the periodic table. The sum of the atoms within it for polyatomic ions.
+    pub atomic_number: u16
+}
+
+#[derive(Clone)]
 pub struct GameBoard {
 
-    // Two dimentional of board tiles.
-    tile_array: [[BoardTile; 6]; 6],
-    color_picker: ColorPicker
+    // Two dimentional of board tiles. Indexed by column and then row.
+    tile_array: Vec<Vec<BoardTile>>,
+    width: usize,
+    height: usize,
+    color_picker: ColorPicker,
+
+    // Every bond between two atoms on the board.
+    bond_graph: BondGraph,
+
+    // The way completed compounds are scored.
+    scoring: ScoringMode
 }
 
 impl Default for GameBoard {
     fn default() -> Self {
-        GameBoard {
 
-            // Put all empty spaces into the array.
-            tile_array: from_fn(|_| from_fn(|_| BoardTile::default())),
-            color_picker: ColorPicker::default()
-        }
+        return GameBoard::New(DEFAULT_BOARD_SIZE.0, DEFAULT_BOARD_SIZE.1);
     }
 }
 
 impl GameBoard {
 
+    // New Function:
+    // Parameters:
+    // - width: Number of columns.
+    // - height: Number of rows.
+    // Description: Creates a board of empty tiles.
+    pub fn New(width: usize, height: usize) -> GameBoard {
+
+        return GameBoard {
+
+            // Put all empty spaces into the array.
+            tile_array: vec![vec![BoardTile::default(); height]; width],
+            width,
+            height,
+            color_picker: ColorPicker::default(),
+            bond_graph: BondGraph::default(),
+            scoring: ScoringMode::Classic
+        };
+    }
+
+    // SetScoring Function:
+    // Parameters:
+    // - scoring: The way completed compounds are scored.
+    // Description: Changes the scoring mode of the board. Boards use classic scoring until this is called.
+    pub fn SetScoring(&mut self, scoring: ScoringMode) {
+
+        self.scoring = scoring;
+    }
+
+    // IsValidSize Function:
+    // Parameters:
+    // - width: Number of columns.
+    // - height: Number of rows.
+    // Description: Returns true if a board can be made with the given size.
+    pub fn IsValidSize(width: usize, height: usize) -> bool {
+
+        let range = MIN_BOARD_SIZE..=MAX_BOARD_SIZE;
+        return range.contains(&width) && range.contains(&height);
+    }
+
+    // CheckBond Function:
+    // Parameters:
+    // - tile: Board tile that the atom would be played on.
+    // - atom: The atom being played. Atoms that cannot bond are inert.
+    // - order: Bond order chosen by the player. None forms the largest possible bond.
+    // Description: Checks that an atom can be played on a tile without changing the board.
+    // Return: BoardError describing why the atom cannot be played.
+    pub fn CheckBond(&self, tile: (usize, usize), atom: &PlayedAtom, order: Option<u16>) -> Result<(), BoardError> {
+
+        if !self.IsOnBoard(tile) {
+
+            return Err(BoardError::OutOfBounds);
+        }
+
+        // Only empty zones and bond zones can be played on.
+        let selected_tile = self.ViewTile(tile);
+        match selected_tile.GetState() {
+
+            // No bond is formed on an empty zone, so a bond order cannot be chosen.
+            TileState::Empty => return GameBoard::CheckOrder(order, 0),
+            TileState::Bond => (),
+            TileState::Restricted => return Err(BoardError::Restricted),
+            TileState::Atom | TileState::Parent | TileState::Inert => return Err(BoardError::Occupied),
+        }
+
+        // Inert atoms do not bond, so they can block a bond zone of any compound.
+        if atom.bond_number == 0 {
+
+            return GameBoard::CheckOrder(order, 0);
+        }
+
+        // Ions only form ionic bonds, so one of the two atoms must be a metal and the other must not.
+        let bond_tile = self.ViewTile(selected_tile.GetBondTile());
+        if (atom.is_ion || bond_tile.IsIon()) && (atom.is_metal == bond_tile.IsMetal()) {
+
+            return Err(BoardError::IonicOnly);
+        }
+
+        // If the compound is a metal, extra conditions must be met.
+        if atom.is_metal {
+
+            let parent_tile = self.ViewTile(bond_tile.GetParentTile());
+
+            match parent_tile.GetCompoundMetal() {
+
+                Some(metal) => {
+
+                    // Metals within the same compound must have the same type and oxidation state.
+                    if metal != GameBoard::MetalComponent(&atom.symbol, atom.bond_number) {
+
+                        return Err(BoardError::MixedMetals);
+                    }
+
+                    // Cannot bond directly to another metal.
+                    if bond_tile.IsMetal() {
+
+                        return Err(BoardError::MetalsCannotBond);
+                    }
+                },
+                None => (),
+            }
+        }
+
+        // The chosen order cannot be larger than the bond that would be formed by default.
+        let largest_order = GameBoard::BondOrder(atom.bond_number, self.GetRemainingBonds(selected_tile.GetBondTile()), None);
+        return GameBoard::CheckOrder(order, largest_order);
+    }
+
+    // CheckOrder Function:
+    // Parameters:
+    // - order: Bond order chosen by the player.
+    // - largest_order: Largest bond that can be formed. Zero if no bond is formed.
+    // Description: Checks that a chosen bond order can be formed. Not choosing an order is always allowed.
+    fn CheckOrder(order: Option<u16>, largest_order: u16) -> Result<(), BoardError> {
+
+        match order {
+
+            Some(order) if (order == 0) || (order > largest_order) => return Err(BoardError::InvalidBondOrder),
+            _ => return Ok(()),
+        }
+    }
+
+    // SimulateBond Function:
+    // Parameters:
+    // - tile: Board tile that the atom would be played on.
+    // - atom: The atom being played.
+    // - order: Bond order chosen by the player. None forms the largest possible bond.
+    // Description: Dry run of the Bond function. Works out the result of playing an atom without changing any tiles
+    //   or using a color from the color picker.
+    // Return: BondPreview struct. BoardError if the atom cannot be played.
+    pub fn SimulateBond(&self, tile: (usize, usize), atom: &PlayedAtom, order: Option<u16>) -> Result<BondPreview, BoardError> {
+
+        self.CheckBond(tile, atom, order)?;
+
+        // An inert atom only takes up the tile.
+        if atom.bond_number == 0 {
+
+            return Ok(BondPreview {
+
+                new_compound: false,
+                bonds_formed: 0,
+                extra_bonds: 0,
+                remaining_bonds: 0,
+                points: None,
+                bond_type: None
+            });
+        }
+
+        let selected_tile = self.ViewTile(tile);
+
+        // An atom played on an empty space becomes the parent of a new compound.
+        if selected_tile.GetState() == TileState::Empty {
+
+            return Ok(BondPreview {
+
+                new_compound: true,
+                bonds_formed: 0,
+                extra_bonds: 0,
+                remaining_bonds: atom.bond_number,
+                points: None,
+                bond_type: None
+            });
+        }
+
+        let bonds = self.PlanBonds(tile, atom, order);
+        let bonds_formed = bonds[0].order;
+        let extra_bonds: u16 = bonds[1..].iter().map(|bond| bond.order).sum();
+
+        // Every compound that the atom bonds to would become one compound.
+        let parents = self.GetBondedParents(&bonds);
+        let bonds_left: u16 = parents.iter().map(|parent| self.CountCompoundBonds(*parent)).sum();
+
+        // The compound is completed if the played atom uses all of its bonds and its bonds use up every bond left
+        // within the compounds it joins.
+        let mut points: Option<u16> = None;
+        if (atom.bond_number == bonds_formed + extra_bonds) && (bonds_left == bonds_formed + extra_bonds) {
+
+            let mut compound_bonds: Vec<BondEdge> = parents.iter().flat_map(|parent| self.bond_graph.GetCompoundBonds(*parent)).collect();
+            compound_bonds.extend(bonds.iter().copied());
+
+            let atomic_sum: u16 = parents.iter().map(|parent| self.ViewTile(*parent).GetAtomicSum()).sum();
+            points = Some(self.ScoreCompound(atomic_sum + atom.atomic_number, &compound_bonds));
+        }
+
+        return Ok(BondPreview {
+
+            new_compound: false,
+            bonds_formed,
+            extra_bonds,
+            remaining_bonds: atom.bond_number - bonds_formed - extra_bonds,
+            points,
+            bond_type: Some(bonds[0].bond_type)
+        });
+    }
+
     // Bond Function:
     // Parameters:
     // - tile: Board tile that the atom is being played on.
-    // - symbol: String which is displayed inside of a board tile.
-    // - bond_number: Number of bonds that the atom can form.
+    // - atom: The atom being played.
     // - presedence: Value which determines who gets presedence over a freed bond zone.
-    // - atomic_number: Atomic number of the atom from the periodic table.
-    // Description: Turns empty zones into parent zones. Turns bond zones into atom zones.
-    // Return: BoardStatus struct.
-    pub fn Bond(&mut self, tile: (usize, usize), symbol: String, bond_number: u16, is_metal: bool, presedence: u8, atomic_number: u16) -> BoardStatus {
+    // - chance: Source of the random color given to a new compound.
+    // - order: Bond order chosen by the player. None forms the largest possible bond.
+    // Description: Turns empty zones into parent zones. Turns bond zones into atom zones, which bond to every
+    //   neighbor they can (see PlanBonds). Inert atoms turn either zone into an inert tile.
+    // Return: Score of the compound if it was completed by the bond. BoardError if the atom cannot be played.
+    pub fn Bond(&mut self, tile: (usize, usize), atom: &PlayedAtom, presedence: u8, chance: &mut Chance, order: Option<u16>) -> Result<Option<u16>, BoardError> {
 
-        // Get the state of the selected tile.
-        let tile_state: TileState;
-        {
-            let selected_tile = self.GetTile(tile);
-            tile_state = selected_tile.GetState();
+        self.CheckBond(tile, atom, order)?;
+
+        // Inert atoms do not join or start a compound. A blocked bond zone is not replaced by UpdateBondZones.
+        if atom.bond_number == 0 {
+
+            self.GetTile(tile).MakeInertZone(atom.symbol.clone());
+            return Ok(None);
         }
 
-        if tile_state == TileState::Empty {
+        if self.GetTile(tile).GetState() == TileState::Empty {
 
             // If bond happens on an empty space, the played atom becomes a parent atom with a unique color.
             let compound_color: (u8, u8, u8);
             {
-                compound_color = self.color_picker.GetColor();
+                compound_color = self.color_picker.GetColor(chance);
             }
 
             let selected_tile = self.GetTile(tile);
 
-            if is_metal {
+            if atom.is_metal {
 
                 // Make the parent zone with metal trait.
-                selected_tile.MakeParentZone(tile, symbol.clone(), bond_number, is_metal, presedence, Some(symbol.clone()), atomic_number);
+                selected_tile.MakeParentZone(tile, atom, presedence, Some(GameBoard::MetalComponent(&atom.symbol, atom.bond_number)));
             }
             else {
 
                 // Make the parent zone without metal trait.
-                selected_tile.MakeParentZone(tile, symbol.clone(), bond_number, is_metal, presedence, None, atomic_number);
+                selected_tile.MakeParentZone(tile, atom, presedence, None);
             }
 
             selected_tile.SetColor(compound_color);
 
-            // Return restrict tile error message.
-            return BoardStatus {
+            return Ok(None);
+        }
 
-                error_message: None,
-                points: None
-            }
+        // Work out every bond the atom forms, starting with the atom that owns the bond zone.
+        let bonds = self.PlanBonds(tile, atom, order);
+
+        // Get the compound parent from the atom being bonded to.
+        let parent_tile_coords: (usize, usize);
+        {
+            let bond_tile = self.GetTile(bonds[0].first);
+            parent_tile_coords = bond_tile.GetParentTile();
         }
-        else if tile_state == TileState::Bond {
 
-            // Get the coordinates of the atom being bonded to.
-            let bond_tile_coords: (usize, usize);
-            let bond_tile_bonds: u16;
-            {
-                // Get coordinates of the bond atom.
-                {
-                    let selected_tile = self.GetTile(tile);
-                    bond_tile_coords = selected_tile.GetBondTile();
-                }
-                
-                // Get number of bonds the bond atom has.
-                {
-                    let bond_tile = self.GetTile(bond_tile_coords);
-                    bond_tile_bonds = bond_tile.GetBondNumber();
-                }
-            }
+        // Get color and metalic property from compound parent.
+        let compound_metal: Option<String>;
+        let compound_color: (u8, u8, u8);
+        {
+            let parent_tile = self.GetTile(parent_tile_coords);
+            compound_metal = parent_tile.GetCompoundMetal();
+            compound_color = parent_tile.GetColor();
+        }
 
-            // Get the compound parent from the atom being bonded to.
-            let parent_tile_coords: (usize, usize);
-            {
-                let bond_tile = self.GetTile(bond_tile_coords);
-                parent_tile_coords = bond_tile.GetParentTile();
-            }
+        // The first metal in a compound becomes the compound metal. CheckBond has already
+        // rejected metals which do not fit into the compound.
+        let mut add_metal: Option<String> = None;
+        if atom.is_metal && (compound_metal == None) {
 
-            // Get color and metalic property from compound parent.
-            let compound_metal: Option<String>;
-            let compound_color: (u8, u8, u8);
-            let mut add_metal: Option<String> = None;
-            {
-                let parent_tile = self.GetTile(parent_tile_coords);
-                compound_metal = parent_tile.GetCompoundMetal();
-                compound_color = parent_tile.GetColor();
+            add_metal = Some(GameBoard::MetalComponent(&atom.symbol, atom.bond_number));
+        }
+
+        // Officially bond the atom to the compound.
+        {
+            let selected_tile = self.GetTile(tile);
+            selected_tile.MakeAtomZone(parent_tile_coords, atom, presedence);
+            selected_tile.SetColor(compound_color);
+        }
+
+        // The bonds are added to the bond graph, which takes the bonds they use away from both atoms.
+        for bond in bonds.iter() {
+
+            self.bond_graph.AddBond(*bond);
+
+            if self.GetRemainingBonds(bond.first) == 0 {
+
+                // Bond zones are removed in case the atom becomes neutral.
+                // If the atom is not neutral, they will be reset by the UpdateBondZones function.
+                self.RemoveBondZones(bond.first);
             }
+        }
+
+        self.GetTile(parent_tile_coords).AddToCompound(atom.atomic_number, add_metal);
+
+        // Bonds to other compounds join them into one compound.
+        let parent_tile_coords = self.MergeCompounds(tile);
+
+        // The compound is neutralized once none of its atoms have bonds left.
+        if self.CountCompoundBonds(tile) == 0 {
 
-            // If the compound is a metal, extra conditions must be met.
-            if is_metal {
+            let bonds = self.bond_graph.GetCompoundBonds(tile);
+            return Ok(Some(self.ScoreCompound(self.ViewTile(parent_tile_coords).GetAtomicSum(), &bonds)));
+        }
 
-                match compound_metal {
+        return Ok(None);
+    }
 
-                    Some(metal) => {
+    // PlanBonds Function:
+    // Parameters:
+    // - tile: Bond zone that the atom is played on.
+    // - atom: The atom being played.
+    // - order: Bond order chosen by the player for the first bond. None to bond to every neighbor.
+    // Description: Works out every bond formed by an atom played on a bond zone. The first bond is with the atom that
+    //   owns the bond zone. Without a chosen order, the atom then bonds to each other neighboring atom with bonds left,
+    //   earliest played first, until it runs out of bonds. This closes rings and joins compounds. Neighbors that the
+    //   atom could not be played next to under the rules of CheckBond are skipped. With a chosen order, only the first
+    //   bond is formed and the rest of the atom's bonds are kept for later atoms.
+    // Return: The bonds, starting with the bond to the owner of the bond zone.
+    fn PlanBonds(&self, tile: (usize, usize), atom: &PlayedAtom, order: Option<u16>) -> Vec<BondEdge> {
+
+        let owner = self.ViewTile(tile).GetBondTile();
+        let owner_tile = self.ViewTile(owner);
+        let first_order = GameBoard::BondOrder(atom.bond_number, self.GetRemainingBonds(owner), order);
+        let bond_type = BondType::Classify(atom.electronegativity, owner_tile.GetElectronegativity(), atom.is_ion || owner_tile.IsIon());
+
+        let mut bonds = vec![BondEdge { first: owner, second: tile, order: first_order, bond_type }];
+        if order != None {
+
+            return bonds;
+        }
 
-                        // Metals within the same compound must have the same type.
-                        if metal == symbol {
+        let mut remaining = atom.bond_number - first_order;
 
-                            let bond_tile = self.GetTile(bond_tile_coords);
+        // The compounds being joined can only hold one metal between them.
+        let mut metal = self.ViewTile(owner_tile.GetParentTile()).GetCompoundMetal();
+        if atom.is_metal && (metal == None) {
 
-                            // Cannot bond directly to another metal.
-                            if bond_tile.IsMetal() {
+            metal = Some(GameBoard::MetalComponent(&atom.symbol, atom.bond_number));
+        }
 
-                                // Direct bond error.
-                                return BoardStatus {
+        let mut neighbors: Vec<(usize, usize)> = [self.GetUpTile(tile), self.GetDownTile(tile), self.GetLeftTile(tile), self.GetRightTile(tile)]
+            .into_iter()
+            .flatten()
+            .filter(|neighbor| (*neighbor != owner) && matches!(self.ViewTile(*neighbor).GetState(), TileState::Atom | TileState::Parent))
+            .filter(|neighbor| self.GetRemainingBonds(*neighbor) > 0)
+            .collect();
+        neighbors.sort_by_key(|neighbor| self.ViewTile(*neighbor).GetPresedence());
 
-                                    error_message: Some(String::from("Error: Metals cannot bond with each other.")),
-                                    points: None
-                                };
-                            }
-                            else {
+        for neighbor in neighbors {
 
-                                add_metal = None;
-                            }
-                        }
-                        else {
+            if remaining == 0 {
 
-                            // Different type error.
-                            return BoardStatus {
+                break;
+            }
 
-                                error_message: Some(String::from("Error: Compounds can only contain one type of metal.")),
-                                points: None
-                            };
-                        }
-                    },
-                    None => add_metal = Some(symbol.clone()), // The metal becomes the compound metal.
-                };
+            // Metals cannot bond to each other, and ions only form ionic bonds.
+            let neighbor_tile = self.ViewTile(neighbor);
+            if (atom.is_metal && neighbor_tile.IsMetal()) || ((atom.is_ion || neighbor_tile.IsIon()) && (atom.is_metal == neighbor_tile.IsMetal())) {
+
+                continue;
             }
 
-            // Officially bond the atom to the compound.
-            {
-                let selected_tile = self.GetTile(tile);
-                selected_tile.MakeAtomZone(parent_tile_coords, symbol, bond_number, is_metal, presedence);
-                selected_tile.SetColor(compound_color);
+            match (&metal, self.ViewTile(neighbor_tile.GetParentTile()).GetCompoundMetal()) {
+
+                (Some(current), Some(other)) if *current != other => continue,
+                (None, Some(other)) => metal = Some(other),
+                _ => (),
             }
 
-            let mut neutral_atoms: u8 = 0;
-            let mut bonds_created: u16 = bond_number;
+            let order = remaining.min(self.GetRemainingBonds(neighbor)).min(MAX_BOND_ORDER);
+            let bond_type = BondType::Classify(atom.electronegativity, neighbor_tile.GetElectronegativity(), atom.is_ion || neighbor_tile.IsIon());
+            bonds.push(BondEdge { first: neighbor, second: tile, order, bond_type });
+            remaining -= order;
+        }
 
-            // Atoms are bonded to each other with the BondAtom function.
-            // This function must be called on both atoms.
-            {
-                let selected_tile = self.GetTile(tile);
-                
-                if selected_tile.BondAtom(bond_tile_bonds) {
+        return bonds;
+    }
 
-                    neutral_atoms = neutral_atoms + 1;
-                }
+    // GetBondedParents Function:
+    // Parameters:
+    // - bonds: Bonds planned by PlanBonds.
+    // Description: Returns the parent tile of each different compound that the bonds reach.
+    fn GetBondedParents(&self, bonds: &[BondEdge]) -> Vec<(usize, usize)> {
 
-                bonds_created = bonds_created - selected_tile.GetBondNumber();
-            }
-            
-            if self.GetTile(bond_tile_coords).BondAtom(bond_number) {
+        let mut parents: Vec<(usize, usize)> = Vec::new();
+        for bond in bonds.iter() {
 
-                neutral_atoms = neutral_atoms + 1;
+            let parent = self.ViewTile(bond.first).GetParentTile();
+            if !parents.contains(&parent) {
 
-                // Bond zones are removed in case the atom becomes neutral.
-                // If the atom is not neutral, they will be reset by the UpdateBondZones function.
-                self.RemoveBondZones(bond_tile_coords);
+                parents.push(parent);
             }
+        }
 
-            {
-                let parent_tile = self.GetTile(parent_tile_coords);
+        return parents;
+    }
 
-                // Update the overall compound. UpdateCompound returns true if the coumpound is neutralized.
-                if parent_tile.UpdateCompound(neutral_atoms, atomic_number, bonds_created, add_metal) {
+    // MergeCompounds Function:
+    // Parameters:
+    // - coords: Coordinates of an atom that was just played.
+    // Description: If the atom joined more than one compound, the compounds become one compound. The parent played
+    //   earliest keeps its place and color, and takes in the atomic numbers and metal of the other compounds. The
+    //   other parents become regular atoms.
+    // Return: Coordinates of the parent of the compound.
+    fn MergeCompounds(&mut self, coords: (usize, usize)) -> (usize, usize) {
 
-                    return BoardStatus {
+        let compound = self.bond_graph.GetConnected(coords);
 
-                        error_message: None,
-                        points: Some(parent_tile.GetCompoundScore())
-                    }
-                }
-            }
+        let mut parents: Vec<(usize, usize)> = Vec::new();
+        for atom in compound.iter() {
 
-            return BoardStatus {
+            let parent = self.ViewTile(*atom).GetParentTile();
+            if !parents.contains(&parent) {
 
-                error_message: None,
-                points: None
+                parents.push(parent);
             }
         }
-        else if tile_state == TileState::Restricted {
 
-            // Return restrict tile error message.
-            return BoardStatus {
+        parents.sort_by_key(|parent| self.ViewTile(*parent).GetPresedence());
+        let parent = parents[0];
+        if parents.len() == 1 {
 
-                error_message: Some(String::from("Error: Selected space is Restricted.")),
-                points: None
-            }
+            return parent;
         }
-        else {
 
-            // Return occupied tile error message.
-            return BoardStatus {
+        for other in parents[1..].iter() {
 
-                error_message: Some(String::from("Error: Selected space is occupied.")),
-                points: None
-            }
+            let atomic_sum = self.ViewTile(*other).GetAtomicSum();
+            let metal = self.ViewTile(*other).GetCompoundMetal();
+            self.GetTile(parent).AddToCompound(atomic_sum, metal);
+        }
+
+        let color = self.ViewTile(parent).GetColor();
+        for atom in compound {
+
+            self.GetTile(atom).JoinCompound(parent, color);
+        }
+
+        return parent;
+    }
+
+    // BondOrder Function:
+    // Parameters:
+    // - bond_number: Number of bonds that the played atom can form.
+    // - partner_bonds: Number of bonds the atom that owns the bond zone has left.
+    // - order: Bond order chosen by the player. CheckBond has already rejected orders which cannot be formed.
+    // Description: Works out the order of a new bond. Unless an order is chosen, the largest possible bond is formed,
+    //   up to a triple bond.
+    fn BondOrder(bond_number: u16, partner_bonds: u16, order: Option<u16>) -> u16 {
+
+        match order {
+
+            Some(order) => return order,
+            None => return bond_number.min(partner_bonds).min(MAX_BOND_ORDER),
         }
     }
 
+    // GetRemainingBonds Function:
+    // Parameters:
+    // - coords: Coordinates of an atom.
+    // Description: Returns the number of bonds an atom has left, which is the number it was played with minus the
+    //   bonds it has within the bond graph.
+    fn GetRemainingBonds(&self, coords: (usize, usize)) -> u16 {
+
+        return self.ViewTile(coords).GetBondCapacity() - self.bond_graph.CountBonds(coords);
+    }
+
+    // CountCompoundBonds Function:
+    // Parameters:
+    // - coords: Coordinates of any atom within a compound.
+    // Description: Returns the number of bonds left over every atom within a compound. A compound is neutral when
+    //   this is zero.
+    fn CountCompoundBonds(&self, coords: (usize, usize)) -> u16 {
+
+        return self.bond_graph.GetConnected(coords).into_iter().map(|atom| self.GetRemainingBonds(atom)).sum();
+    }
+
+    // ScoreCompound Function:
+    // Parameters:
+    // - atomic_sum: Sum of the atomic numbers within the compound.
+    // - bonds: Every bond within the compound.
+    // Description: Scores a neutral compound from the structure of its bonds.
+    fn ScoreCompound(&self, atomic_sum: u16, bonds: &[BondEdge]) -> u16 {
+
+        let largest_bond = bonds.iter().map(|bond| bond.order).max().unwrap_or(0);
+        let polar_bonds = bonds.iter().filter(|bond| bond.bond_type == BondType::PolarCovalent).count() as u16;
+        let ionic_bonds = bonds.iter().filter(|bond| bond.bond_type == BondType::Ionic).count() as u16;
+
+        return self.scoring.Score(atomic_sum, largest_bond, polar_bonds, ionic_bonds);
+    }
+
+    // MetalComponent Function:
+    // Parameters:
+    // - symbol: Symbol of the metal.
+    // - bond_number: Number of bonds the metal was played with.
+    // Description: Names the metal of a compound. Transition metals can be played with different numbers of bonds,
+    //   so the number of bonds is part of the name. (Example: Fe2 and Fe3 are different metals)
+    // Return: The name stored within the parent tile.
+    fn MetalComponent(symbol: &str, bond_number: u16) -> String {
+
+        return format!("{}{}", symbol.trim(), bond_number);
+    }
+
     // RemoveBondZones Function:
     // Parameters:
     // - coords: Coordinates of the atom to remove bond zones from.
     // Description: Removes all bond zones around an atom.
     fn RemoveBondZones(&mut self, coords: (usize, usize)) {
 
-        self.RemoveBondZone(GameBoard::GetUpTile(coords));
-        self.RemoveBondZone(GameBoard::GetDownTile(coords));
-        self.RemoveBondZone(GameBoard::GetLeftTile(coords));
-        self.RemoveBondZone(GameBoard::GetRightTile(coords));
+        self.RemoveBondZone(self.GetUpTile(coords));
+        self.RemoveBondZone(self.GetDownTile(coords));
+        self.RemoveBondZone(self.GetLeftTile(coords));
+        self.RemoveBondZone(self.GetRightTile(coords));
     }
 
     // RemoveBondZone Function:
@@ -283,60 +687,59 @@ impl GameBoard {
     // Parameters: 
     // - coords_1 and coords_2: Coordinates of the tiles to resrict.
     // Description: Changes the state of two tiles to restriced.
-    pub fn Restrict(&mut self, coords_1: (usize, usize), coords_2: (usize, usize)) -> BoardStatus {
-
-        // Test if coords are valid to restrict.
-        match self.TestRestrict(coords_1) {
+    pub fn Restrict(&mut self, coords_1: (usize, usize), coords_2: (usize, usize)) -> Result<(), BoardError> {
 
-            Some(status) => return status,
-            None => (),
-        }
-    
-        // Test if coords are valid to restrict.
-        match self.TestRestrict(coords_2) {
-
-            Some(status) => return status,
-            None => (),
-        }
+        self.CheckRestrict(coords_1, coords_2)?;
 
         // Restrict the given coordinates.
         self.ApplyRestrict(coords_1);
         self.ApplyRestrict(coords_2);
 
-        return BoardStatus {
+        return Ok(());
+    }
+
+    // CheckRestrict Function:
+    // Parameters: 
+    // - coords_1 and coords_2: Coordinates of the tiles to resrict.
+    // Description: Checks that two tiles can be restricted without changing the board.
+    pub fn CheckRestrict(&self, coords_1: (usize, usize), coords_2: (usize, usize)) -> Result<(), BoardError> {
+
+        // Test if coords are valid to restrict.
+        self.TestRestrict(coords_1)?;
+        self.TestRestrict(coords_2)?;
 
-            error_message: None,
-            points: None
+        if coords_1 == coords_2 {
+
+            return Err(BoardError::SameZone);
         }
+
+        return Ok(());
     }
 
     // TestRestrict Function:
     // Parameters:
     // - coords: The coordinates of the tile to prepare for the restrict function.
     // Description: Checks if a tile is an atom, parent or restricted state. 
-    fn TestRestrict(&mut self, coords: (usize, usize)) -> Option<BoardStatus> {
+    fn TestRestrict(&self, coords: (usize, usize)) -> Result<(), BoardError> {
 
-        let restrict_tile = self.GetTile(coords);
+        if !self.IsOnBoard(coords) {
+
+            return Err(BoardError::OutOfBounds);
+        }
 
-        // Check for an atom or parent at given coords.
-        if (restrict_tile.GetState() == TileState::Atom) || (restrict_tile.GetState() == TileState::Parent) {
+        let restrict_tile = self.ViewTile(coords);
 
-            return Some(BoardStatus {
+        // Check for an atom, parent, or inert atom at given coords.
+        if (restrict_tile.GetState() == TileState::Atom) || (restrict_tile.GetState() == TileState::Parent) || (restrict_tile.GetState() == TileState::Inert) {
 
-                error_message: Some(String::from("Error: Cannot restrict on top of atoms.")),
-                points: None
-            })
+            return Err(BoardError::RestrictOnAtom);
         }
         else if restrict_tile.GetState() == TileState::Restricted {
 
-            return Some(BoardStatus {
-
-                error_message: Some(String::from("Error: Zone is already restricted.")),
-                points: None
-            })
+            return Err(BoardError::AlreadyRestricted);
         }
 
-        return None;
+        return Ok(());
     }
 
     // ApplyRestrict Function:
@@ -351,58 +754,53 @@ impl GameBoard {
     // Destroy Function:
     // Parameters:
     // - coords: The coordinates of the atim to destroy.
-    // Description: Finds the parent of the provided atom. Destroys all elements with that parent on the game board.
-    pub fn Destroy(&mut self, coords: (usize, usize)) -> BoardStatus {
+    // Description: Follows the bonds of the provided atom to find its compound. Destroys every atom of the compound.
+    pub fn Destroy(&mut self, coords: (usize, usize)) -> Result<(), BoardError> {
         
-        // Get coordinates of the parent tile.
-        let parent_tile: (usize, usize);
-        {
-            let specified_atom = self.GetTile(coords);
-
-            if (specified_atom.GetState() != TileState::Parent) && (specified_atom.GetState() != TileState::Atom) {
-
-                return BoardStatus {
+        self.CheckDestroy(coords)?;
 
-                    error_message: Some(String::from("Error: No compound specified.")),
-                    points: None
-                }
-            }
+        // Remove all bond zones and bonds of each atom within the compound and clear the tile.
+        for atom in self.bond_graph.GetConnected(coords) {
 
-            parent_tile = specified_atom.GetParentTile();
+            self.RemoveBondZones(atom);
+            self.GetTile(atom).ClearTile();
+            self.bond_graph.RemoveTile(atom);
         }
 
-        // Go to every tile. If it has the specified parent tile, remove all bond zones and clear the tile.
-        for i in 0..6 {
+        self.UpdateBondZones();
 
-            for j in 0..6 {
+        return Ok(());
+    }
 
-                if (self.GetTile((i, j)).GetState() == TileState::Atom) || (self.GetTile((i, j)).GetState() == TileState::Parent) {
+    // CheckDestroy Function:
+    // Parameters:
+    // - coords: The coordinates of the atom to destroy.
+    // Description: Checks that there is a compound at the given coordinates without changing the board.
+    // Return: Coordinates of the parent tile of the compound.
+    pub fn CheckDestroy(&self, coords: (usize, usize)) -> Result<(usize, usize), BoardError> {
 
-                    if self.GetTile((i, j)).GetParentTile() == parent_tile {
+        if !self.IsOnBoard(coords) {
 
-                        self.RemoveBondZones((i, j));
-                        self.GetTile((i, j)).ClearTile();
-                    }
-                }
-            }
+            return Err(BoardError::OutOfBounds);
         }
 
-        self.UpdateBondZones();
+        let specified_atom = self.ViewTile(coords);
 
-        return BoardStatus {
+        if (specified_atom.GetState() != TileState::Parent) && (specified_atom.GetState() != TileState::Atom) {
 
-            error_message: None,
-            points: None
+            return Err(BoardError::NoCompound);
         }
+
+        return Ok(specified_atom.GetParentTile());
     }
 
     // EndTurn Function:
     // Description: Resets bondzones that changed earlier in the turn.
     pub fn EndTurn(&mut self) {
 
-        for i in 0..6 {
+        for i in 0..self.width {
 
-            for j in 0..6 {
+            for j in 0..self.height {
 
                 if self.GetTile((i, j)).GetState() == TileState::Restricted {
 
@@ -419,9 +817,9 @@ impl GameBoard {
     // bond zones are organized based on presedence.
     fn UpdateBondZones(&mut self) {
 
-        for i in 0..6 {
+        for i in 0..self.width {
 
-            for j in 0..6 {
+            for j in 0..self.height {
 
                 // Get state of tile.
                 let curr_state: TileState;
@@ -433,19 +831,16 @@ impl GameBoard {
                 // Check for atom or parent state.
                 if (curr_state == TileState::Atom) || (curr_state == TileState::Parent) {
 
-                    let available_bonds: u16;
-                    {
-                        let curr_tile = self.GetTile((i, j));
-                        available_bonds = curr_tile.GetBondNumber();
-                    }
+                    // Bonds left over are worked out from the bond graph.
+                    let available_bonds = self.GetRemainingBonds((i, j));
 
                     // If the atom has bonds, check the bond zones.
                     if available_bonds != 0 {
 
-                        self.UpdateBondZone(GameBoard::GetUpTile((i, j)), (i, j), String::from("vv"));
-                        self.UpdateBondZone(GameBoard::GetDownTile((i, j)), (i, j), String::from("^^"));
-                        self.UpdateBondZone(GameBoard::GetLeftTile((i, j)), (i, j), String::from(">>"));
-                        self.UpdateBondZone(GameBoard::GetRightTile((i, j)), (i, j), String::from("<<"));
+                        self.UpdateBondZone(self.GetUpTile((i, j)), (i, j), String::from("vv"));
+                        self.UpdateBondZone(self.GetDownTile((i, j)), (i, j), String::from("^^"));
+                        self.UpdateBondZone(self.GetLeftTile((i, j)), (i, j), String::from(">>"));
+                        self.UpdateBondZone(self.GetRightTile((i, j)), (i, j), String::from("<<"));
                     }
                 }
             }
@@ -556,21 +951,148 @@ impl GameBoard {
         }
     }
 
-    // PrintTile Function:
+    // FormatTile Function:
     // Parameters:
-    // - coords: Coordinates to the tile to print.
-    // Description: Prints an individual tile on the gameboard.
-    pub fn PrintTile(&mut self, coords: (usize, usize)) {
+    // - coords: Coordinates to the tile to display.
+    // Description: Formats an individual tile on the gameboard. The bracket on a side with a bond to the next tile
+    //   shows the order of the bond instead.
+    // Return: The tile with its colors as a string.
+    pub fn FormatTile(&self, coords: (usize, usize)) -> String {
 
         // Get the tile and its color.
-        let tile = self.GetTile(coords);
+        let tile = self.ViewTile(coords);
         let col = tile.GetColor();
 
         // Set up TUI colors.
         let fmt_col = Fg(Rgb(col.0, col.1, col.2));
         let white = Fg(Rgb(255, 255, 255));
 
-        print!("{}[{}{}{}]{}", fmt_col, white, tile.GetSymbol(), fmt_col, white);
+        let left = self.FormatBond(coords, self.GetLeftTile(coords), '[');
+        let right = self.FormatBond(coords, self.GetRightTile(coords), ']');
+
+        return format!("{}{}{}{}{}{}{}", fmt_col, left, white, tile.GetSymbol(), fmt_col, right, white);
+    }
+
+    // FormatBond Function:
+    // Parameters:
+    // - coords: Coordinates of the tile being displayed.
+    // - option: Coordinates of a neighboring tile if it exists.
+    // - bracket: Character shown when the tiles are not bonded.
+    // Description: Returns the character drawn between a tile and its neighbor. Single, double, and triple bonds are
+    //   drawn as -, =, and ≡.
+    fn FormatBond(&self, coords: (usize, usize), option: Option<(usize, usize)>, bracket: char) -> char {
+
+        match option.and_then(|neighbor| self.bond_graph.GetBond(coords, neighbor)) {
+
+            Some(bond) => {
+
+                match bond.order {
+
+                    1 => return '-',
+                    2 => return '=',
+                    _ => return '≡',
+                }
+            },
+            None => return bracket,
+        }
+    }
+
+    // Save Function:
+    // Parameters:
+    // - writer: The save file being written.
+    // Description: Writes the size of the board, every tile one column at a time, the unused colors, and the bonds.
+    pub fn Save(&self, writer: &mut SaveWriter) {
+
+        writer.Write("board", &[self.GetWidth().ToField(), self.GetHeight().ToField()]);
+
+        for i in 0..self.GetWidth() {
+
+            for j in 0..self.GetHeight() {
+
+                self.ViewTile((i, j)).Save(writer);
+            }
+        }
+
+        self.color_picker.Save(writer);
+        self.bond_graph.Save(writer);
+    }
+
+    // Load Function:
+    // Parameters:
+    // - reader: The save file being read.
+    // Description: Reads a board written by Save.
+    // Return: The board. Error if the size of the board is not allowed, or if a tile or bond points to a tile that
+    //   does not hold what it needs.
+    pub fn Load(reader: &mut SaveReader) -> Result<GameBoard, SaveError> {
+
+        let record = reader.Read("board")?;
+        let size: (usize, usize) = (record.Get(0)?, record.Get(1)?);
+        if !GameBoard::IsValidSize(size.0, size.1) {
+
+            return Err(record.Error("Board size is not allowed."));
+        }
+
+        let mut board = GameBoard::New(size.0, size.1);
+
+        for i in 0..board.GetWidth() {
+
+            for j in 0..board.GetHeight() {
+
+                *board.GetTile((i, j)) = BoardTile::Load(reader, size)?;
+            }
+        }
+
+        // Tiles are only checked against each other once every tile has been read.
+        for i in 0..board.GetWidth() {
+
+            for j in 0..board.GetHeight() {
+
+                match board.CheckLoadedTile((i, j)) {
+
+                    Ok(()) => (),
+                    Err(message) => return Err(record.Error(&format!("Tile {}: {}", (i, j).ToField(), message))),
+                }
+            }
+        }
+
+        board.color_picker = ColorPicker::Load(reader)?;
+        board.bond_graph = BondGraph::Load(reader, |coords| {
+
+            board.IsOnBoard(coords) && matches!(board.GetTileState(coords), TileState::Atom | TileState::Parent)
+        })?;
+
+        return Ok(board);
+    }
+
+    // CheckLoadedTile Function:
+    // Parameters:
+    // - coords: The coordinates of a tile that was read from a save file.
+    // Description: Checks that a bond zone belongs to an atom and that an atom belongs to a parent atom that is its
+    //   own parent.
+    // Return: Error message if the tile does not fit the tiles around it.
+    fn CheckLoadedTile(&self, coords: (usize, usize)) -> Result<(), &'static str> {
+
+        let tile = self.ViewTile(coords);
+
+        if tile.IsBondTile() && !matches!(self.GetTileState(tile.GetBondTile()), TileState::Atom | TileState::Parent) {
+
+            return Err("Bond zone does not belong to an atom.");
+        }
+
+        match tile.GetState() {
+
+            TileState::Atom | TileState::Parent => {
+
+                let parent = tile.GetParentTile();
+                if (self.GetTileState(parent) != TileState::Parent) || (self.ViewTile(parent).GetParentTile() != parent) {
+
+                    return Err("Atom does not belong to a parent atom.");
+                }
+            },
+            _ => (),
+        }
+
+        return Ok(());
     }
 
     // GetTile Function:
@@ -583,21 +1105,91 @@ impl GameBoard {
         return &mut self.tile_array[coords.0][coords.1];
     }
 
+    // ViewTile Function:
+    // Parameters:
+    // - coords: The coordinates of a tile.
+    // Description: Gets a tile object from the board without allowing changes.
+    // Return: Refference to a BoardTile object.
+    fn ViewTile(&self, coords: (usize, usize)) -> &BoardTile {
+
+        return &self.tile_array[coords.0][coords.1];
+    }
+
+    // GetTileState Function:
+    // Parameters:
+    // - coords: The coordinates of a tile.
+    // Description: Returns the state of a tile on the board.
+    pub fn GetTileState(&self, coords: (usize, usize)) -> TileState {
+
+        return self.ViewTile(coords).GetState();
+    }
+
+    // GetBonds Function:
+    // Parameters:
+    // - coords: The coordinates of a tile.
+    // Description: Returns every bond of the atom on a tile, in the order they were formed. Empty if the tile does
+    //   not hold an atom that has bonded.
+    pub fn GetBonds(&self, coords: (usize, usize)) -> Vec<BondEdge> {
+
+        return self.bond_graph.GetBonds(coords);
+    }
+
+    // GetBond Function:
+    // Parameters:
+    // - a and b: The coordinates of two tiles.
+    // Description: Returns the bond between the atoms on two tiles. None if they are not bonded.
+    pub fn GetBond(&self, a: (usize, usize), b: (usize, usize)) -> Option<BondEdge> {
+
+        return self.bond_graph.GetBond(a, b);
+    }
+
+    // GetBondOwner Function:
+    // Parameters:
+    // - coords: The coordinates of a tile.
+    // Description: Returns the coordinates of the atom that a bond zone points to. None if the tile is not a bond zone.
+    pub fn GetBondOwner(&self, coords: (usize, usize)) -> Option<(usize, usize)> {
+
+        let tile = self.ViewTile(coords);
+        if tile.GetState() != TileState::Bond {
+
+            return None;
+        }
+
+        return Some(tile.GetBondTile());
+    }
+
+    // GetWidth and GetHeight Functions:
+    // Description: Return the number of columns and rows on the board.
+    //---------------------------------------------------------------------------------------------------
+    pub fn GetWidth(&self) -> usize {
+
+        return self.width;
+    }
+
+    pub fn GetHeight(&self) -> usize {
+
+        return self.height;
+    }
+    //---------------------------------------------------------------------------------------------------
+
+    // IsOnBoard Function:
+    // Parameters:
+    // - coords: The coordinates of a tile.
+    // Description: Returns true if the coordinates are within the board.
+    pub fn IsOnBoard(&self, coords: (usize, usize)) -> bool {
+
+        return (coords.0 < self.GetWidth()) && (coords.1 < self.GetHeight());
+    }
+
     // FindBondZone Function:
     // Description: Finds and returns the first bond zone starting from (0, 0).
-    pub fn FindBondZone(&mut self) -> Option<(usize, usize)> {
+    pub fn FindBondZone(&self) -> Option<(usize, usize)> {
 
-        for i in 0..6 {
+        for i in 0..self.width {
 
-            for j in 0..6 {
-
-                let curr_state: TileState;
-                {
-                    let curr_tile = self.GetTile((i, j));
-                    curr_state = curr_tile.GetState();
-                }
+            for j in 0..self.height {
 
-                if curr_state == TileState::Bond {
+                if self.ViewTile((i, j)).GetState() == TileState::Bond {
 
                     // Return the coordinates of tile found.
                     return Some((i, j));
@@ -611,34 +1203,29 @@ impl GameBoard {
 
     // FindEmptyZone Function:
     // Description: Finds and returns the first empty zone starting from (0, 0).
-    pub fn FindEmptyZone(&mut self) -> (usize, usize) {
-
-        for i in 0..6 {
+    // Return: None if every tile holds an atom or a zone.
+    pub fn FindEmptyZone(&self) -> Option<(usize, usize)> {
 
-            for j in 0..6 {
+        for i in 0..self.width {
 
-                let curr_state: TileState;
-                {
-                    let curr_tile = self.GetTile((i, j));
-                    curr_state = curr_tile.GetState();
-                }
+            for j in 0..self.height {
 
-                if curr_state == TileState::Empty {
+                if self.ViewTile((i, j)).GetState() == TileState::Empty {
 
                     // Return the first empty space found.
-                    return (i, j);
+                    return Some((i, j));
                 }
             }
         }
 
-        // This case should never hapen.
-        return (0, 0);
+        // No tile found.
+        return None;
     }
 
     // GetUpTile, GetDownTile, GetLeftTile, and GetRightTile:
     // Description: Given a pair of coordinates, return an adjacent tile if it exists.
     // -----------------------------------------------------------------------------------------------------------------
-    fn GetUpTile(coords: (usize, usize)) -> Option<(usize, usize)> {
+    fn GetUpTile(&self, coords: (usize, usize)) -> Option<(usize, usize)> {
 
         if coords.1 == 0 {
 
@@ -648,9 +1235,9 @@ impl GameBoard {
         return Some((coords.0, coords.1 - 1));
     }
 
-    fn GetDownTile(coords: (usize, usize)) -> Option<(usize, usize)> {
+    fn GetDownTile(&self, coords: (usize, usize)) -> Option<(usize, usize)> {
 
-        if coords.1 == 5 {
+        if coords.1 + 1 >= self.height {
 
             return None;
         }
@@ -658,7 +1245,7 @@ impl GameBoard {
         return Some((coords.0, coords.1 + 1));
     }
 
-    fn GetLeftTile(coords: (usize, usize)) -> Option<(usize, usize)> {
+    fn GetLeftTile(&self, coords: (usize, usize)) -> Option<(usize, usize)> {
 
         if coords.0 == 0 {
 
@@ -668,9 +1255,9 @@ impl GameBoard {
         return Some((coords.0 - 1, coords.1));
     }
 
-    fn GetRightTile(coords: (usize, usize)) -> Option<(usize, usize)> {
+    fn GetRightTile(&self, coords: (usize, usize)) -> Option<(usize, usize)> {
 
-        if coords.0 == 5 {
+        if coords.0 + 1 >= self.width {
 
             return None;
         }
@@ -0,0 +1,151 @@
+// My modules.
+use crate::save_file::SaveField;
+
+// Smallest electronegativity differences, times one hundred, for a polar covalent bond and an ionic bond.
+const POLAR_DIFFERENCE: u16 = 40;
+const IONIC_DIFFERENCE: u16 = 170;
+
+// Enum describing a bond between two atoms, from the difference in their electronegativity.
+// - NonpolarCovalent: Electrons are shared evenly. Difference below 0.4.
+// - PolarCovalent: Electrons are shared unevenly. Difference from 0.4 to below 1.7.
+// - Ionic: Electrons are given from one atom to the other. Difference of 1.7 or more, or any bond with an ion.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BondType {
+    NonpolarCovalent,
+    PolarCovalent,
+    Ionic,
+}
+
+// Bond types are saved by name.
+impl SaveField for BondType {
+
+    fn ToField(&self) -> String {
+
+        return format!("{:?}", self);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        match field {
+
+            "NonpolarCovalent" => return Some(BondType::NonpolarCovalent),
+            "PolarCovalent" => return Some(BondType::PolarCovalent),
+            "Ionic" => return Some(BondType::Ionic),
+            _ => return None,
+        }
+    }
+}
+
+impl BondType {
+
+    // Classify Function:
+    // Parameters:
+    // - first: Electronegativity of one atom. None for atoms without one, such as polyatomic ions.
+    // - second: Electronegativity of the other atom.
+    // - has_ion: True if either atom is a polyatomic ion.
+    // Description: Works out the type of a bond between two atoms.
+    pub fn Classify(first: Option<u16>, second: Option<u16>, has_ion: bool) -> BondType {
+
+        if has_ion {
+
+            return BondType::Ionic;
+        }
+
+        match (first, second) {
+
+            (Some(first), Some(second)) => {
+
+                let difference = first.abs_diff(second);
+                if difference >= IONIC_DIFFERENCE {
+
+                    return BondType::Ionic;
+                }
+                else if difference >= POLAR_DIFFERENCE {
+
+                    return BondType::PolarCovalent;
+                }
+
+                return BondType::NonpolarCovalent;
+            },
+            _ => return BondType::NonpolarCovalent,
+        }
+    }
+
+    // GetName Function:
+    // Description: Returns the name of the bond type shown within the action log.
+    pub fn GetName(&self) -> &'static str {
+
+        match self {
+
+            BondType::NonpolarCovalent => return "nonpolar covalent",
+            BondType::PolarCovalent => return "polar covalent",
+            BondType::Ionic => return "ionic",
+        }
+    }
+}
+
+// Enum for the ways a completed compound can be scored.
+// - Classic: (sum of atomic numbers) * (largest bond).
+// - Polarity: (sum of atomic numbers) * (largest bond + bonus). Each polar covalent bond adds one to the bonus and
+//   each ionic bond adds two, so compounds with uneven bonds score more.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScoringMode {
+    Classic,
+    Polarity,
+}
+
+// Scoring modes are saved by the name typed by the user.
+impl SaveField for ScoringMode {
+
+    fn ToField(&self) -> String {
+
+        match self {
+
+            ScoringMode::Classic => return String::from("classic"),
+            ScoringMode::Polarity => return String::from("polarity"),
+        }
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        return ScoringMode::FromName(field);
+    }
+}
+
+impl ScoringMode {
+
+    // FromName Function:
+    // Parameters:
+    // - name: Name of the scoring mode typed by the user.
+    // Description: Converts the name of a scoring mode.
+    // Return: None if the name is not recognised.
+    pub fn FromName(name: &str) -> Option<ScoringMode> {
+
+        match name {
+
+            "classic" => return Some(ScoringMode::Classic),
+            "polarity" => return Some(ScoringMode::Polarity),
+            _ => return None,
+        }
+    }
+
+    // Score Function:
+    // Parameters:
+    // - atomic_sum: Sum of the atomic numbers within the compound.
+    // - largest_bond: Largest bond within the compound.
+    // - polar_bonds: Number of polar covalent bonds within the compound.
+    // - ionic_bonds: Number of ionic bonds within the compound.
+    // Description: Works out the score of a completed compound. Scores that do not fit are kept at the largest score.
+    pub fn Score(&self, atomic_sum: u16, largest_bond: u16, polar_bonds: u16, ionic_bonds: u16) -> u16 {
+
+        match self {
+
+            ScoringMode::Classic => return atomic_sum.saturating_mul(largest_bond),
+            ScoringMode::Polarity => {
+
+                let bonus = polar_bonds.saturating_add(ionic_bonds.saturating_mul(2));
+                return atomic_sum.saturating_mul(largest_bond.saturating_add(bonus));
+            },
+        }
+    }
+}
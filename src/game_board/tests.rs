@@ -0,0 +1,358 @@
+// My modules.
+use super::{GameBoard, BondPreview, BoardError, PlayedAtom, TileState, BondType, ScoringMode, BondEdge};
+use super::bond_graph::BondGraph;
+use crate::card_deck::Card;
+use crate::chance::Chance;
+
+// Atom Function:
+// Parameters:
+// - name: Name of an atom or polyatomic ion.
+// - bond_number: Number of bonds the atom is played with. None for its usual number of bonds.
+// Description: Returns the atom put onto the board when the card is played.
+fn Atom(name: &str, bond_number: Option<u16>) -> PlayedAtom {
+
+    let card = Card::FromName(name).unwrap();
+    return card.GetAtom(bond_number.unwrap_or(card.number_of_bonds));
+}
+
+// Play Function:
+// Parameters:
+// - board: The board to play on.
+// - tile: Tile that the atom is played on.
+// - atom: The atom played.
+// - presedence: Turn number the atom is played on.
+// - order: Bond order chosen by the player.
+// Description: Previews an atom with SimulateBond, plays it with Bond, and ends the turn. The points of the preview
+//   must match the points awarded by Bond.
+// Return: The preview of the play.
+fn Play(board: &mut GameBoard, tile: (usize, usize), atom: &PlayedAtom, presedence: u8, order: Option<u16>) -> BondPreview {
+
+    let preview = board.SimulateBond(tile, atom, order).unwrap();
+    let points = board.Bond(tile, atom, presedence, &mut Chance::Seeded(1), order).unwrap();
+    assert_eq!(preview.points, points);
+
+    board.EndTurn();
+    return preview;
+}
+
+#[test]
+fn JoiningTwoCompoundsCompletesThem() {
+
+    // C played between two separate O atoms completes CO2.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("O", None), 1, None);
+    Play(&mut board, (2, 0), &Atom("O", None), 2, None);
+    let preview = Play(&mut board, (1, 0), &Atom("C", None), 3, None);
+
+    assert_eq!(preview.bonds_formed, 2);
+    assert_eq!(preview.extra_bonds, 2);
+    assert_eq!(preview.remaining_bonds, 0);
+    assert_eq!(preview.points, Some(44));
+    assert_eq!(board.GetBond((1, 0), (2, 0)).map(|bond| bond.order), Some(2));
+
+    // The compound started first keeps its parent.
+    assert_eq!(board.GetTileState((0, 0)), TileState::Parent);
+    assert_eq!(board.GetTileState((2, 0)), TileState::Atom);
+    for tile in [(0, 0), (1, 0), (2, 0)] {
+
+        assert_eq!(board.CheckDestroy(tile), Ok((0, 0)));
+    }
+}
+
+#[test]
+fn JoiningPartialCompoundsKeepsEarliestParent() {
+
+    // C joins two N atoms. The second N keeps two bonds, so the joined compound is not complete.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("N", None), 1, None);
+    Play(&mut board, (2, 0), &Atom("N", None), 2, None);
+    let preview = Play(&mut board, (1, 0), &Atom("C", None), 3, None);
+
+    assert_eq!(preview.bonds_formed, 3);
+    assert_eq!(preview.extra_bonds, 1);
+    assert_eq!(preview.points, None);
+    assert_eq!(board.GetTileState((2, 0)), TileState::Atom);
+    assert_eq!(board.CheckDestroy((2, 0)), Ok((0, 0)));
+
+    // Atoms bonded to either N later belong to the joined compound.
+    Play(&mut board, (2, 1), &Atom("H", None), 4, None);
+    assert_eq!(board.CheckDestroy((2, 1)), Ok((0, 0)));
+}
+
+#[test]
+fn LastAtomClosesRing() {
+
+    // Single bonds leave each C with bonds for the atom that closes the ring.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("C", None), 1, None);
+    Play(&mut board, (1, 0), &Atom("C", None), 2, Some(1));
+    Play(&mut board, (0, 1), &Atom("C", None), 3, Some(1));
+    let preview = Play(&mut board, (1, 1), &Atom("C", None), 4, None);
+
+    assert_eq!(preview.bonds_formed, 3);
+    assert_eq!(preview.extra_bonds, 1);
+    assert_eq!(board.GetBonds((1, 1)).len(), 2);
+    assert_eq!(board.GetBond((0, 1), (1, 1)).map(|bond| bond.order), Some(1));
+    assert_eq!(board.CheckDestroy((1, 1)), Ok((0, 0)));
+}
+
+#[test]
+fn DifferentMetalsAreNotJoined() {
+
+    // O bonds to Na, so joining the Mg compound would put two metals into one compound.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("Na", None), 1, None);
+    Play(&mut board, (2, 0), &Atom("Mg", None), 2, None);
+    let preview = Play(&mut board, (1, 0), &Atom("O", None), 3, None);
+
+    assert_eq!(preview.extra_bonds, 0);
+    assert_eq!(preview.remaining_bonds, 1);
+    assert_eq!(board.GetBond((1, 0), (2, 0)), None);
+    assert_eq!(board.CheckDestroy((1, 0)), Ok((0, 0)));
+    assert_eq!(board.CheckDestroy((2, 0)), Ok((2, 0)));
+}
+
+#[test]
+fn ChosenOrderOnlyBondsZoneOwner() {
+
+    // A chosen order keeps the played atom out of other neighboring compounds.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("C", None), 1, None);
+    Play(&mut board, (2, 0), &Atom("O", None), 2, None);
+    let preview = Play(&mut board, (1, 0), &Atom("O", None), 3, Some(1));
+
+    assert_eq!(preview.bonds_formed, 1);
+    assert_eq!(preview.extra_bonds, 0);
+    assert_eq!(preview.remaining_bonds, 1);
+    assert_eq!(board.GetBonds((1, 0)).len(), 1);
+    assert_eq!(board.CheckDestroy((2, 0)), Ok((2, 0)));
+}
+
+#[test]
+fn IonsOnlyFormIonicBonds() {
+
+    // OH can only bond to a metal. Li completes LiOH.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("OH", None), 1, None);
+    assert_eq!(board.CheckBond((1, 0), &Atom("H", None), None), Err(BoardError::IonicOnly));
+
+    let preview = Play(&mut board, (1, 0), &Atom("Li", None), 2, None);
+    assert_eq!(preview.points, Some(12));
+}
+
+#[test]
+fn CompoundKeepsOneOxidationState() {
+
+    // Fe played with two bonds leaves C with two bonds for more atoms.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("C", None), 1, None);
+    Play(&mut board, (1, 0), &Atom("Fe", Some(2)), 2, None);
+
+    // A second Fe must use the same oxidation state.
+    assert_eq!(board.CheckBond((0, 1), &Atom("Fe", Some(3)), None), Err(BoardError::MixedMetals));
+    assert_eq!(board.CheckBond((0, 1), &Atom("Fe", Some(2)), None), Ok(()));
+}
+
+#[test]
+fn CarbonateIsNotShownAsCobalt() {
+
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("CO3", None), 1, None);
+
+    let tile = board.FormatTile((0, 0));
+    assert!(tile.contains("C3"));
+    assert!(!tile.contains("CO"));
+}
+
+#[test]
+fn BondTypesFollowElectronegativity() {
+
+    let classify = |a: &str, b: &str| BondType::Classify(Atom(a, None).electronegativity, Atom(b, None).electronegativity, false);
+    assert_eq!(classify("C", "H"), BondType::NonpolarCovalent);
+    assert_eq!(classify("H", "O"), BondType::PolarCovalent);
+    assert_eq!(classify("Li", "O"), BondType::Ionic);
+
+    // A difference of 0.4 is polar and a difference of 1.7 is ionic.
+    assert_eq!(BondType::Classify(Some(100), Some(139), false), BondType::NonpolarCovalent);
+    assert_eq!(BondType::Classify(Some(100), Some(140), false), BondType::PolarCovalent);
+    assert_eq!(BondType::Classify(Some(269), Some(100), false), BondType::PolarCovalent);
+    assert_eq!(BondType::Classify(Some(270), Some(100), false), BondType::Ionic);
+
+    // Any bond with an ion is ionic.
+    assert_eq!(BondType::Classify(None, Some(220), true), BondType::Ionic);
+}
+
+#[test]
+fn PolarityScoringCountsPolarBonds() {
+
+    // H2O has two polar bonds, so it scores 10 * (1 + 2) instead of 10.
+    let mut board = GameBoard::default();
+    board.SetScoring(ScoringMode::Polarity);
+    Play(&mut board, (1, 0), &Atom("O", None), 1, None);
+    Play(&mut board, (0, 0), &Atom("H", None), 2, None);
+    let preview = Play(&mut board, (2, 0), &Atom("H", None), 3, None);
+    assert_eq!(preview.points, Some(30));
+    assert_eq!(ScoringMode::Classic.Score(10, 1, 2, 0), 10);
+
+    // Scores that do not fit are kept at the largest score.
+    assert_eq!(ScoringMode::Classic.Score(u16::MAX, 3, 0, 0), u16::MAX);
+    assert_eq!(ScoringMode::Polarity.Score(u16::MAX, 3, 2, 1), u16::MAX);
+}
+
+#[test]
+fn InertAtomsDoNotBond() {
+
+    // A noble gas on an empty zone makes no bond zones.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("Ne", None), 1, None);
+    assert_eq!(board.GetTileState((0, 0)), TileState::Inert);
+    assert_eq!(board.GetTileState((1, 0)), TileState::Empty);
+    assert_eq!(board.GetTileState((0, 1)), TileState::Empty);
+
+    // A noble gas on a bond zone takes the zone away and is not bonded to.
+    Play(&mut board, (3, 0), &Atom("O", None), 2, None);
+    assert_eq!(board.GetTileState((2, 0)), TileState::Bond);
+    Play(&mut board, (2, 0), &Atom("Ar", None), 3, None);
+    assert_eq!(board.GetTileState((2, 0)), TileState::Inert);
+    assert!(board.GetBonds((3, 0)).is_empty());
+
+    // Nothing can be played on, restricted, or destroyed on an inert tile.
+    for tile in [(0, 0), (2, 0)] {
+
+        assert_eq!(board.CheckBond(tile, &Atom("H", None), None), Err(BoardError::Occupied));
+        assert_eq!(board.CheckRestrict(tile, (5, 5)), Err(BoardError::RestrictOnAtom));
+        assert_eq!(board.CheckDestroy(tile), Err(BoardError::NoCompound));
+    }
+}
+
+#[test]
+fn BondOrderMustFitBothAtoms() {
+
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("O", None), 1, None);
+
+    // O has two bonds left, so C can form a single or double bond with it but not a triple bond.
+    assert_eq!(board.CheckBond((1, 0), &Atom("C", None), Some(0)), Err(BoardError::InvalidBondOrder));
+    assert_eq!(board.CheckBond((1, 0), &Atom("C", None), Some(3)), Err(BoardError::InvalidBondOrder));
+    assert_eq!(board.CheckBond((1, 0), &Atom("C", None), Some(2)), Ok(()));
+
+    // H only has one bond.
+    assert_eq!(board.CheckBond((1, 0), &Atom("H", None), Some(2)), Err(BoardError::InvalidBondOrder));
+
+    // Once H has used one of the bonds of O, only one is left.
+    Play(&mut board, (1, 0), &Atom("H", None), 2, None);
+    assert_eq!(board.CheckBond((0, 1), &Atom("C", None), Some(2)), Err(BoardError::InvalidBondOrder));
+    assert_eq!(board.CheckBond((0, 1), &Atom("C", None), Some(1)), Ok(()));
+
+    // No bond is formed on an empty zone.
+    assert_eq!(board.CheckBond((4, 4), &Atom("C", None), Some(1)), Err(BoardError::InvalidBondOrder));
+}
+
+#[test]
+fn SmallerOrderLeavesBondZones() {
+
+    // A single bond between C and O leaves three bonds on C and one on O.
+    let mut board = GameBoard::default();
+    Play(&mut board, (1, 1), &Atom("C", None), 1, None);
+    let preview = Play(&mut board, (2, 1), &Atom("O", None), 2, Some(1));
+    assert_eq!(preview.remaining_bonds, 1);
+    assert_eq!(board.GetBond((1, 1), (2, 1)).map(|bond| bond.order), Some(1));
+
+    for zone in [(0, 1), (1, 0), (1, 2)] {
+
+        assert_eq!(board.GetBondOwner(zone), Some((1, 1)));
+    }
+    for zone in [(3, 1), (2, 0), (2, 2)] {
+
+        assert_eq!(board.GetBondOwner(zone), Some((2, 1)));
+    }
+
+    // The bond left on O can still be used.
+    Play(&mut board, (3, 1), &Atom("H", None), 3, None);
+    assert_eq!(board.GetBond((2, 1), (3, 1)).map(|bond| bond.order), Some(1));
+}
+
+#[test]
+fn BondGraphFollowsEdges() {
+
+    let edge = |first, second, order| BondEdge { first, second, order, bond_type: BondType::NonpolarCovalent };
+
+    // A chain of three atoms with a separate pair next to it.
+    let mut graph = BondGraph::default();
+    graph.AddBond(edge((0, 0), (1, 0), 2));
+    graph.AddBond(edge((1, 0), (2, 0), 1));
+    graph.AddBond(edge((4, 4), (4, 5), 3));
+
+    let bond = graph.GetBond((1, 0), (0, 0)).unwrap();
+    assert_eq!((bond.first, bond.second, bond.order), ((0, 0), (1, 0), 2));
+    assert_eq!(bond.GetOther((0, 0)), (1, 0));
+    assert_eq!(bond.GetOrderName(), "double");
+    assert_eq!(graph.GetBond((0, 0), (2, 0)), None);
+
+    assert_eq!(graph.CountBonds((1, 0)), 3);
+    assert_eq!(graph.GetConnected((2, 0)), vec![(2, 0), (1, 0), (0, 0)]);
+    assert_eq!(graph.GetCompoundBonds((0, 0)).len(), 2);
+
+    // Removing the middle atom splits the chain and leaves the pair alone.
+    graph.RemoveTile((1, 0));
+    assert!(graph.GetBonds((0, 0)).is_empty());
+    assert_eq!(graph.CountBonds((2, 0)), 0);
+    assert_eq!(graph.GetConnected((0, 0)), vec![(0, 0)]);
+    assert_eq!(graph.CountBonds((4, 5)), 3);
+}
+
+#[test]
+fn MergedCompoundsShareOneGraph() {
+
+    // Two separate O atoms are each their own compound.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("O", None), 1, None);
+    Play(&mut board, (2, 0), &Atom("O", None), 2, None);
+    assert_eq!(board.bond_graph.GetConnected((0, 0)), vec![(0, 0)]);
+
+    // C bonds to both of them, and MergeCompounds makes them one compound.
+    Play(&mut board, (1, 0), &Atom("C", None), 3, None);
+    let mut connected = board.bond_graph.GetConnected((2, 0));
+    connected.sort();
+    assert_eq!(connected, vec![(0, 0), (1, 0), (2, 0)]);
+    assert_eq!(board.bond_graph.CountBonds((1, 0)), 4);
+    assert_eq!(board.bond_graph.CountBonds((0, 0)), 2);
+    assert_eq!(board.bond_graph.GetCompoundBonds((0, 0)).len(), 2);
+
+    // Each O owned the bond zone that C was played on.
+    for owner in [(0, 0), (2, 0)] {
+
+        let bond = board.GetBond(owner, (1, 0)).unwrap();
+        assert_eq!((bond.first, bond.second, bond.order), (owner, (1, 0), 2));
+    }
+
+    // Destroying the compound removes every bond.
+    board.Destroy((2, 0)).unwrap();
+    for tile in [(0, 0), (1, 0), (2, 0)] {
+
+        assert!(board.GetBonds(tile).is_empty());
+        assert_eq!(board.bond_graph.CountBonds(tile), 0);
+        assert_eq!(board.GetTileState(tile), TileState::Empty);
+    }
+}
+
+#[test]
+fn BondsAreDrawnByOrder() {
+
+    // C forms a single bond with H, a double bond with O, and a triple bond with N.
+    for (partner, symbol) in [("H", '-'), ("O", '='), ("N", '≡')] {
+
+        let mut board = GameBoard::default();
+        Play(&mut board, (0, 0), &Atom("C", None), 1, None);
+        Play(&mut board, (1, 0), &Atom(partner, None), 2, None);
+
+        assert_eq!(board.FormatBond((0, 0), Some((1, 0)), ']'), symbol, "{}", partner);
+        assert_eq!(board.FormatBond((1, 0), Some((0, 0)), '['), symbol, "{}", partner);
+    }
+
+    // Tiles without a bond keep their brackets.
+    let mut board = GameBoard::default();
+    Play(&mut board, (0, 0), &Atom("C", None), 1, None);
+    assert_eq!(board.FormatBond((0, 0), None, '['), '[');
+    assert_eq!(board.FormatBond((0, 0), Some((1, 0)), ']'), ']');
+}
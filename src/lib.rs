@@ -0,0 +1,33 @@
+// Naming and return conventions used throughout the project.
+#![allow(non_snake_case)]
+#![allow(clippy::needless_return)]
+#![allow(clippy::partialeq_to_none)]
+#![allow(clippy::single_match)]
+#![allow(clippy::needless_late_init)]
+
+// Class representing the game board.
+pub mod game_board;
+
+// Class representing the decks of cards used by the game.
+pub mod card_deck;
+
+// Data for every element that can appear in the game.
+pub mod element_table;
+
+// Class used to record events that happen in the game.
+pub mod action_log;
+
+// Class which owns the state of a match and advances it turn by turn.
+pub mod game;
+
+// Move logic used by the computer opponent.
+pub mod computer;
+
+// Connection and line protocol used for matches between two processes.
+pub mod network;
+
+// Plain text format used to save and load games.
+pub mod save_file;
+
+// Source of every random outcome within a game. Outcomes are recorded so that games can be replayed.
+pub mod chance;
@@ -1,35 +1,227 @@
+// Naming and return conventions used throughout the project.
+#![allow(non_snake_case)]
+#![allow(clippy::needless_return)]
+#![allow(clippy::partialeq_to_none)]
+#![allow(clippy::type_complexity)]
+#![allow(clippy::needless_late_init)]
+#![allow(clippy::single_match)]
+
 // Termion used as TUI and for colored tiles.
 extern crate termion;
-use termion::color::{Fg, Rgb};
 use termion::clear;
 
-// Rng crate.
-extern crate rand;
-use rand::Rng;
-
 // Used for user input.
 use std::io::stdin;
+use std::env;
+use std::process;
+
+// Game engine library.
+extern crate rust_fcgreen6;
+use rust_fcgreen6::game_board::{GameBoard, ScoringMode, MIN_BOARD_SIZE, MAX_BOARD_SIZE};
+use rust_fcgreen6::card_deck::{CardDeck, DeckList};
+use rust_fcgreen6::action_log::ActionLog;
+use rust_fcgreen6::game::{Game, GameConfig, Side, Phase, Abilities, Action, Replay, ReplayStep, UndoHistory, UndoRule};
+use rust_fcgreen6::computer::Difficulty;
+use rust_fcgreen6::network::{Connection, Message};
+use rust_fcgreen6::chance::Chance;
+
+#[cfg(test)]
+mod tests;
+
+// Rows of the periodic table drawn around the board. Each row holds the part of the table left of the board and the
+// part right of it. The board sits in the gap where the transition metals would be.
+const TABLE_ROWS: [(&str, &str); 6] = [
+    ("|1 |   ", "               |2 |"),
+    ("-------", "-------------------"),
+    ("|Li|Be|", "|B |C |N |O |F |Ne|"),
+    ("|3 |4 |", "|5 |6 |7 |8 |9 |10|"),
+    ("-------", "-------------------"),
+    ("|Na|Mg|", "|Al|Si|P |S |Cl|Ar|"),
+];
+
+// Row of the table used when the board has more rows than the table.
+const TABLE_EMPTY_ROW: (&str, &str) = ("|  |  |", "|  |  |  |  |  |  |");
+
+// Width of the gap in the table. Boards that do not fit stretch the table.
+const TABLE_GAP: usize = 29;
+
+// Enum for who controls the opponent side.
+// - Computer: The computer plays at the given difficulty.
+// - Hotseat: A second person plays at the same terminal.
+// - Network: A guest plays from another process. Only used while hosting.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Computer(Difficulty),
+    Hotseat,
+    Network,
+}
+
+impl Mode {
+
+    // GetName Function:
+    // Description: Returns the name of the mode shown on the starting screen.
+    fn GetName(self) -> String {
+
+        match self {
+
+            Mode::Computer(difficulty) => return difficulty.GetName(),
+            Mode::Hotseat => return String::from("hotseat"),
+            Mode::Network => return String::from("network"),
+        }
+    }
+
+    // GetSideNames Function:
+    // Description: Returns the names the two sides are dealt with in this mode. A save file keeps these names, so
+    //   they also show which mode a loaded game was saved in.
+    fn GetSideNames(self) -> (String, String) {
+
+        match self {
+
+            Mode::Computer(_) => return (Side::Player.GetName(), Side::Opponent.GetName()),
+            Mode::Hotseat => return (String::from("Player 1"), String::from("Player 2")),
+            Mode::Network => return (String::from("Host"), String::from("Guest")),
+        }
+    }
+
+    // GetUndoRule Function:
+    // Parameters:
+    // - chosen: The undo rule chosen with --undo.
+    // Description: Undoing past the end of a turn would take back the other person's moves, so that is only allowed
+    //   against the computer. Other modes only allow undoing within a turn.
+    // Return: The undo rule used in this mode.
+    fn GetUndoRule(self, chosen: UndoRule) -> UndoRule {
+
+        match (self, chosen) {
+
+            (Mode::Computer(_), _) => return chosen,
+            (_, UndoRule::Always) => return UndoRule::Turn,
+            (_, _) => return chosen,
+        }
+    }
+}
+
+// Settings chosen with command line flags.
+struct Options {
+
+    mode: Mode,
+
+    // Port to wait for a guest on. Address of the host to join.
+    host: Option<u16>,
+    join: Option<String>,
+
+    // Save file to continue from.
+    load: Option<String>,
+
+    // Replay file to view instead of playing.
+    replay: Option<String>,
+
+    // When undo is allowed.
+    undo: UndoRule,
+
+    // Seed every game is dealt from. A random seed is used for each game when there is none.
+    seed: Option<u64>,
 
-// Class representing the game board.
-mod game_board;
-use game_board::{GameBoard, BoardStatus};
+    // Rules for new games, from a preset or a config file. Loaded games keep the rules they were saved with.
+    config: GameConfig,
 
-// Class representing the decks of cards used by the game.
-mod card_deck;
-use card_deck::{CardDeck, Card};
+    // Board size and deck lists chosen with their own flags. Used instead of the ones from the preset or config file.
+    board: Option<(usize, usize)>,
+    player_deck: Option<DeckList>,
+    opponent_deck: Option<DeckList>,
 
-// Class used to record events that happen in the game.
-mod action_log;
-use action_log::ActionLog;
+    // True if transition metals were turned on with their own flag.
+    transition_metals: bool,
+
+    // Scoring mode chosen with its own flag.
+    scoring: Option<ScoringMode>
+}
 
 fn main() {
 
+    // Command line flags.
+    let options: Options;
+    match ParseArguments(env::args().skip(1).collect()) {
+
+        Ok(value) => options = value,
+        Err(message) => {
+
+            eprintln!("{}", message);
+            eprintln!("Usage: cargo run -- [--difficulty easy|medium|hard|expert] [--hotseat] [--host port] [--join address:port] [--load file] [--replay file] [--undo off|turn|always] [--seed number] [--preset standard|lunch|tournament] [--config file] [--board widthxheight] [--player-deck file] [--opponent-deck file] [--transition-metals] [--scoring classic|polarity]");
+            process::exit(1);
+        },
+    }
+
+    // The replay viewer does not start a game.
+    match options.replay {
+
+        Some(path) => {
+
+            RunReplay(&path);
+            return;
+        },
+        None => (),
+    }
+
+    // A guest only displays the screens sent by the host.
+    match options.join {
+
+        Some(address) => {
+
+            RunGuest(&address);
+            return;
+        },
+        None => (),
+    }
+
+    // Computer difficulty, hotseat, or network. Can be changed each time a game is started.
+    let mut mode = options.mode;
+
+    // Connection to the guest while hosting.
+    let mut connection: Option<Connection> = None;
+    match options.host {
+
+        Some(port) => {
+
+            println!("Waiting for a player to join on port {}.", port);
+            match Connection::Host(port) {
+
+                Ok(value) => connection = Some(value),
+                Err(error) => {
+
+                    eprintln!("Error: Could not host a game. {}", error);
+                    process::exit(1);
+                },
+            }
+        },
+        None => (),
+    }
+
+    // Game to continue instead of dealing the first game.
+    let mut loaded: Option<Game> = None;
+    match options.load {
+
+        Some(path) => {
+
+            match Game::LoadFile(&path) {
+
+                Ok(game) => loaded = Some(game),
+                Err(error) => {
+
+                    eprintln!("{}", error);
+                    process::exit(1);
+                },
+            }
+        },
+        None => (),
+    }
+
     // User input variable.
-    let mut user_input = String::new();
+    let mut user_input: String;
+
+    // Starting screen. A networked game starts as soon as the guest joins and a loaded game continues right away.
+    if (mode != Mode::Network) && loaded.is_none() {
 
-    // Starting screen.
-    {
-        let mut board: GameBoard = Default::default();
+        let board = GameBoard::New(options.config.board_size.0, options.config.board_size.1);
         let mut log: ActionLog = Default::default();
 
         let mut invalid: bool = true;
@@ -37,21 +229,20 @@ fn main() {
 
             // Refresh the screen and get user input.
             //-----------------------------------------------------------------------
-            println!("{}", clear::All);
+            let mut screen: Vec<String> = Vec::new();
+            DrawScore(&mut screen, &board, &Side::Player.GetName(), 0, &Side::Opponent.GetName(), 0);
+            DrawGameBoard(&mut screen, &board, &log);
+            DrawEmptyHand(&mut screen, &board);
 
-            PrintScore(0, 0);
-            PrintGameBoard(&mut board, &log);
-            PrintEmptyHand();
- 
-            println!("Enter the command \"start\" to begin a new game.");
+            screen.push(format!("Enter the command \"start\" to begin a new game. Mode: {}.", mode.GetName()));
+            screen.push(String::from("Use \"start easy\", \"start medium\", \"start hard\", or \"start expert\" to choose a difficulty, or \"start hotseat\" for two players."));
+            ShowScreen(&screen);
 
-            user_input = String::new();
-            stdin().read_line(&mut user_input);
+            user_input = ReadInput();
             //-----------------------------------------------------------------------
 
             // Check that the start command was used properly.
-            let input_fields = MatchCommand(&mut user_input);
-            if (input_fields.0 == Some(String::from("start"))) && (input_fields.1.0 == None) {
+            if MatchStart(&user_input, &mut mode) {
 
                 invalid = false;
             }
@@ -67,377 +258,902 @@ fn main() {
     let mut quit: bool = false;
     while !quit {
         
-        // Initialize classes.
-        let mut board: GameBoard = Default::default();
-        let mut log: ActionLog = Default::default();
+        // Deals both hands and chooses who goes first. The opponent side is only controlled by the computer outside of hotseat.
+        let mut game: Game;
+        let mut computer_player;
+        let chance = match options.seed {
+
+            Some(seed) => Chance::Seeded(seed),
+            None => Chance::default(),
+        };
+        let (player_name, opponent_name) = mode.GetSideNames();
+        game = Game::NewWithChance(&player_name, &opponent_name, options.config.clone(), chance);
+        match mode {
+
+            Mode::Computer(difficulty) => computer_player = Some(difficulty.CreateStrategy()),
+            Mode::Hotseat | Mode::Network => computer_player = None,
+        }
+
+        // A game loaded with --load replaces the first game dealt.
+        match loaded.take() {
 
-        // Initialize decks.
-        let mut player_deck: CardDeck = Default::default();
-        let mut computer_deck: CardDeck = Default::default();
+            Some(game_file) => {
 
-        // Draw four cards to each hand.
-        for i in 0..4 {
-            
-            player_deck.AddToHand();
-            computer_deck.AddToHand();
+                game = game_file;
+                CheckLoadedMode(&mut game, mode);
+            },
+            None => (),
         }
 
-        // Score variables.
-        let mut player_score: u16 = 0;
-        let mut computer_score: u16 = 0;
+        let mut history = UndoHistory::New(mode.GetUndoRule(options.undo));
 
-        // Ability variables. For simplicity, the computer does not use abilities.
-        let mut player_evolve: u8 = 1;
-        let mut player_destroy: u8 = 1;
-        let mut player_restrict: u8 = 2;
+        // Side whose hand was last shown on screen.
+        let mut shown_side: Option<Side> = None;
 
-        // Choose who goes first.
-        let mut turn_number = 1;
-        let mut player_turn = false;
-        {
-            // Initialize random number generator.
-            let mut random = rand::thread_rng();
+        while !game.IsOver() {
 
-            // Generate a random number to determine who moves first.
-            let coin: u8 = random.gen_range(0..2);
-            if coin == 0 {
+            let side = game.GetCurrentSide();
 
-                player_turn = true;
-                log.PushAction(String::from("Player is going first."), false);
+            // The computer takes its whole turn at once.
+            if side == Side::Opponent {
+
+                match computer_player.as_mut() {
+
+                    Some(strategy) => {
+
+                        strategy.TakeTurn(&mut game);
+                        continue;
+                    },
+                    None => (),
+                }
             }
-            else {
 
-                log.PushAction(String::from("Computer is going first."), false);
+            // Hide the hand while the keyboard is passed to the other player.
+            if (mode == Mode::Hotseat) && (shown_side != Some(side)) {
+
+                PassScreen(&game, side);
             }
-        }
+            shown_side = Some(side);
 
-        // There are sixteen turns in each game. Eight for each player.
-        for i in 0..16 {
+            let prompt: String;
+            if game.GetPhase() == Phase::Ability {
 
-            // Player turn conditional.
-            if player_turn {
+                prompt = format!("{}'s Ability Phase. Type \"pass\" to skip your ability phase.", game.GetSideName(side));
+            }
+            else {
 
-                player_deck.AddToHand();
+                prompt = format!("{}'s Main Phase. Use the play command to play an atom.", game.GetSideName(side));
+            }
+            let waiting = format!("Waiting for {}.", game.GetSideName(side));
 
-                {
-                    // While loop for ability phase.
-                    let mut invalid: bool = true;
-                    while invalid {
-                        
-                        // Refresh the screen and get user input.
-                        //-----------------------------------------------------------------------
-                        println!("{}", clear::All);
+            // The guest is shown every change to the game, including the turns of the host.
+            let guest_turn = (side == Side::Opponent) && (mode == Mode::Network);
+            match connection.as_mut() {
 
-                        PrintScore(player_score, computer_score);
-                        PrintGameBoard(&mut board, &log);
-                        PrintHand(&mut player_deck, player_evolve, player_destroy, player_restrict);
+                Some(guest) => {
 
-                        println!("Ability Phase. Type \"pass\" to skip your ability phase.");
-
-                        user_input = String::new();
-                        stdin().read_line(&mut user_input);
-                        //-----------------------------------------------------------------------
-
-                        // Outer conditional checks what command has been used by the user.
-                        let input_fields = MatchCommand(&mut user_input);
-                        if (input_fields.0 == Some(String::from("restrict"))) && (player_restrict > 0) {
-
-                            // Check that user used the correct number of imput fields.
-                            if (input_fields.1.0 != None) && (input_fields.1.1 != None) && (input_fields.1.2 == None) {
-
-                                // Get the two coordinates used by the restrict function.
-                                let coords_1 = input_fields.1.0.unwrap();
-                                let coords_2 = input_fields.1.1.unwrap();
-
-                                // Check that coords can be created for the spaces provided by user.
-                                if CreateCoords(coords_1.clone()).0 && CreateCoords(coords_2.clone()).0 {
-
-                                    // Create restrict tiles on the board.
-                                    let status = board.Restrict(CreateCoords(coords_1).1, CreateCoords(coords_2).1);
-
-                                    if status.error_message != None {
-
-                                        // Log any error that occurs within the restrict function.
-                                        log.PushAction(status.error_message.unwrap(), true);
-                                    }
-                                    else {
-
-                                        // A valid command was used, so invalid flag is set to false.
-                                        invalid = false;
-                                        log.PushAction(String::from("Player used restrict ability."), false);
-                                        player_restrict -= 1;
-                                    }
-                                }
-                                else {
-
-                                    log.PushAction(String::from("Error: Invalid parameters."), true);
-                                }
-                            }
-                            else {
-
-                                log.PushAction(String::from("Error: Invalid parameters."), true);
-                            }
-                        } else if (input_fields.0 == Some(String::from("destroy"))) && (player_destroy > 0) {
-
-                            // Check only one parameter exists for destroy function.
-                            if (input_fields.1.0 != None) && (input_fields.1.1 == None) {
-
-                                // Unwrap the coords from user input.
-                                let coords = input_fields.1.0.unwrap();
-                                if (CreateCoords(coords.clone()).0) {
-
-                                    // Destroy with the given coords.
-                                    let status = board.Destroy(CreateCoords(coords.clone()).1);
-
-                                    if status.error_message != None {
-
-                                        // Log any error associated with the destroy function.
-                                        log.PushAction(status.error_message.unwrap(), true);
-                                    }
-                                    else {
-
-                                        // A valid command was used, so invalid flag is set to false.
-                                        invalid = false;
-                                        log.PushAction(String::from("Player has destroyed a compound."), false);
-                                        player_destroy -= 1;
-                                    }
-                                }
-                                else {
-
-                                    log.PushAction(String::from("Error: Invalid parameters."), true);
-                                } 
-                            }
-                            else {
-
-                                log.PushAction(String::from("Error: Invalid parameters."), true);
-                            }
-                        } else if (input_fields.0 == Some(String::from("evolve"))) && (player_evolve > 0) {
-
-                            // Check that the fields for evolve are satisfied.
-                            if (input_fields.1.0 != None) && (input_fields.1.1 == None) {
-
-                                // If this is true, the evolution was successful.
-                                if player_deck.EvolveCard(input_fields.1.0.unwrap()) {
-
-                                    // A valid command was used, so invalid flag is set to false.
-                                    invalid = false;
-                                    log.PushAction(String::from("Player has evolved an atom."), false);
-                                    player_evolve -= 1;
-                                }
-                                else {
-
-                                    // Else the parameters are invalid.
-                                    log.PushAction(String::from("Error: Invalid parameters."), true);
-                                }
-                            }
-                            else {
-
-                                log.PushAction(String::from("Error: Invalid parameters."), true);
-                            }
-                        } else if input_fields.0 == Some(String::from("pass")) {
-
-                            // A valid command was used, so invalid flag is set to false.
-                            invalid = false;
-                            log.PushAction(String::from("Player has passed their ability phase."), false);
-                        } else {
-
-                            log.PushAction(String::from("Error: Invalid command."), true);
-                        }
-                    }
-                }
+                    let mut screen = RenderScreen(&game, Side::Opponent);
+                    screen.push(if guest_turn { prompt.clone() } else { waiting.clone() });
+                    SendMessage(guest, &Message::Screen(screen));
+                },
+                None => (),
+            }
 
-                {
-                    // While loop for main phase.
-                    let mut invalid: bool = true;
-                    while invalid {
-
-                        // Refresh the screen and get user input.
-                        //-----------------------------------------------------------------------
-                        println!("{}", clear::All);
-
-                        PrintScore(player_score, computer_score);
-                        PrintGameBoard(&mut board, &log);
-                        PrintHand(&mut player_deck, player_evolve, player_destroy, player_restrict);
-
-                        println!("Main Phase. Use the play command to play an atom.");
-
-                        user_input = String::new();
-                        stdin().read_line(&mut user_input);
-                        //-----------------------------------------------------------------------
-
-                        // Atoms must be played with the play command.
-                        let input_fields = MatchCommand(&mut user_input);
-                        if input_fields.0 == Some(String::from("play")) {
-
-                            // Verify fields used for play command.
-                            if (input_fields.1.0 != None) && (input_fields.1.1 != None) && (input_fields.1.2 == None) {
-
-                                // Get the parameters from user input.
-                                let name = input_fields.1.0.clone().unwrap();
-                                let coords = input_fields.1.1.clone().unwrap();
-
-                                let card_option = player_deck.GetCard(name.clone());
-                                let coords_return = CreateCoords(coords); // Numeric coordinates, not tile coordinates.
-
-                                // card_option is an option representing a card for a given symbol or nothing.
-                                // coords_return.0 gets the boolean flag for the CreateCoords function.
-                                if (card_option != None) && coords_return.0 {
-
-                                    // If the coords and card are valid, attempt to bond.
-                                    let card: Card = card_option.unwrap();
-                                    let status: BoardStatus = board.Bond(coords_return.1, card.symbol, card.number_of_bonds, card.is_metal, turn_number, card.atomic_number);
-
-                                    // Check status of the bond operation.
-                                    if status.error_message != None {
-
-                                        log.PushAction(status.error_message.unwrap(), true);
-                                    }
-                                    else if status.points != None {
-
-                                        // If the points field is specified, the bond was successful and a neutral compound was created.
-                                        log.PushAction(String::from("Player completed a compound!"), false);
-                                        player_deck.RemoveCard(name.clone());
-                                        player_score += status.points.unwrap();
-                                        invalid = false;
-                                    }
-                                    else {
-
-                                        // Bond was successful. Compound is not yet neutral.
-                                        player_deck.RemoveCard(name.clone());
-                                        invalid = false;
-                                    }
-                                }
-                                else {
-
-                                    log.PushAction(String::from("Error: Invalid parameters."), true);
-                                }
-                            }
-                            else {
-
-                                log.PushAction(String::from("Error: Invalid parameters."), true);
-                            }
-                        }
-                        else {
-
-                            log.PushAction(String::from("Error: Invalid command."), true);
-                        }
-                    }
-                }
+            // Refresh the screen and get user input.
+            //-----------------------------------------------------------------------
+            let mut screen: Vec<String>;
+            if guest_turn {
 
-                // Change turn.
-                player_turn = false;
-                board.EndTurn();
+                screen = RenderScreen(&game, Side::Player);
+                screen.push(waiting);
             }
             else {
 
-                computer_deck.AddToHand();
-                
-                /*
-                Computer Algorithm:
-                - Description: Starting from (0, 0), find the first bond zone. Attempt to bond all cards in 
-                  hand to this zone. Only metal atoms are unable to bond within certain bond zones. Since there are only
-                  four metals in the deck and five cards in hand, one atom is gaurenteed to bond.
-                  If there are no bond zones, play on the first empty tile starting from (0, 0).
-                */
-                let bond_zone_option = board.FindBondZone();
-                if bond_zone_option != None {
+                screen = RenderScreen(&game, side);
+                screen.push(prompt);
+            }
+            ShowScreen(&screen);
+
+            match connection.as_mut() {
 
-                    let bond_zone_coords = bond_zone_option.unwrap();
+                Some(guest) if guest_turn => user_input = ReceiveCommand(guest),
+                _ => user_input = ReadInput(),
+            }
+            //-----------------------------------------------------------------------
 
-                    // Iterate over the five cards in hand.
-                    for i in 0..5 {
+            // Save and load change the whole game instead of being an action within it. Files are only used by the host.
+            if !guest_turn {
 
-                        // Attempt to bond with card at given index.
-                        let card = computer_deck.GetFromIndex(i);
-                        let status: BoardStatus = board.Bond(bond_zone_coords, card.symbol, card.number_of_bonds, card.is_metal, turn_number, card.atomic_number);
+                match MatchCommand(&user_input).0.as_deref() {
 
-                        // If there is no error with the bond operation, break out of the loop.
-                        if status.error_message == None {
+                    Some("save") => {
 
-                            if status.points != None {
+                        SaveGame(&user_input, &mut game);
+                        continue;
+                    },
+                    Some("load") => {
 
-                                log.PushAction(String::from("Computer completed a compound!"), false);
-                                computer_score += status.points.unwrap();
-                            }
+                        LoadGame(&user_input, &mut game, mode);
+                        history.Clear();
+                        shown_side = None;
+                        continue;
+                    },
+                    Some("record") => {
 
-                            computer_deck.RemoveCard(card.name);
-                            break;
-                        }
-                    } 
+                        RecordGame(&user_input, &mut game);
+                        continue;
+                    },
+                    _ => (),
                 }
-                else {
+            }
 
-                    // If there are no bond zones, play on an empty tile.
-                    let empty_tile = board.FindEmptyZone();
-                    let card = computer_deck.GetFromIndex(0);
+            // Undo and redo replace the game with a copy from before or after an action. Bonds only looks at the board.
+            match MatchCommand(&user_input).0.as_deref() {
 
-                    // Since the tile is empty, bonding is gaurenteed.
-                    board.Bond(empty_tile, card.symbol, card.number_of_bonds, card.is_metal, turn_number, card.atomic_number);
-                    computer_deck.RemoveCard(card.name);
-                }
+                Some("bonds") => {
+
+                    ShowBonds(&user_input, &mut game);
+                    continue;
+                },
+                Some("undo") => {
+
+                    match history.Undo(&mut game) {
+
+                        Ok(_) => game.PushAction(format!("{} took back an action.", game.GetSideName(side)), false),
+                        Err(error) => game.PushAction(error.to_string(), true),
+                    }
+                    continue;
+                },
+                Some("redo") => {
 
-                // Change turn.
-                player_turn = true;
-                board.EndTurn();
+                    match history.Redo(&mut game) {
+
+                        Ok(_) => game.PushAction(format!("{} redid an action.", game.GetSideName(side)), false),
+                        Err(error) => game.PushAction(error.to_string(), true),
+                    }
+                    continue;
+                },
+                _ => (),
+            }
+
+            // Log any error that occurs within the command. A copy of the game is kept so that the action can be undone.
+            match ParseAction(&user_input) {
+
+                Ok(action) => {
+
+                    let before = game.clone();
+                    match game.Apply(action) {
+
+                        Ok(_) => history.Record(before),
+                        Err(error) => game.PushAction(error.to_string(), true),
+                    }
+                },
+                Err(message) => game.PushAction(message, true),
             }
         }
 
-        log.PushAction(String::from("Game over!"), false);
+        let result: String;
+        match game.GetWinner() {
+
+            Some(winner) => result = format!("{} wins!", game.GetSideName(winner)),
+            None => result = String::from("Its a tie!"),
+        }
 
         let mut invalid = true;
         while invalid {
-            
-            // Draw so that board is printed correctly.
-            player_deck.AddToHand();
-            computer_deck.AddToHand();
-
-            // Refresh the screen and get user input.
-            //-----------------------------------------------------------------------
-            println!("{}", clear::All);
-
-            PrintScore(player_score, computer_score);
-            PrintGameBoard(&mut board, &log);
-            PrintHand(&mut player_deck, player_evolve, player_destroy, player_restrict);
 
-            if player_score > computer_score {
+            // Show the final board to the guest while the host decides whether to play again.
+            match connection.as_mut() {
 
-                println!("Player wins! Type \"start\" to play again. Type \"quit\" to exit the game.");
-            } else if player_score > computer_score {
+                Some(guest) => {
 
-                println!("Computer wins! Type \"start\" to play again. Type \"quit\" to exit the game.");
+                    let mut screen = RenderScreen(&game, Side::Opponent);
+                    screen.push(format!("{} Waiting for Host to start a new game.", result));
+                    SendMessage(guest, &Message::Screen(screen));
+                },
+                None => (),
             }
-            else {
 
-                println!("Its a tie! Type \"start\" to play again. Type \"quit\" to exit the game.");
-            }
+            // Refresh the screen and get user input.
+            //-----------------------------------------------------------------------
+            let mut screen = RenderScreen(&game, Side::Player);
+            screen.push(format!("{} Type \"start\" to play again. Type \"quit\" to exit the game.", result));
+            ShowScreen(&screen);
 
-            user_input = String::new();
-            stdin().read_line(&mut user_input);
+            user_input = ReadInput();
             //-----------------------------------------------------------------------
 
             // Condional to sort through quit and start commands.
-            let input_fields = MatchCommand(&mut user_input);
+            let input_fields = MatchCommand(&user_input);
             if (input_fields.0 == Some(String::from("quit"))) && (input_fields.1.0 == None) {
 
                 quit = true;
                 invalid = false;
-            } else if (input_fields.0 == Some(String::from("start"))) && (input_fields.1.0 == None) {
+            } else if input_fields.0 == Some(String::from("record")) {
+
+                RecordGame(&user_input, &mut game);
+            } else if MatchStart(&user_input, &mut mode) {
 
                 invalid = false;
             } else {
 
-                log.PushAction(String::from("Error: Invalid command."), true);
+                game.PushAction(String::from("Error: Invalid command."), true);
+            }
+        }
+    }
+
+    // Let the guest exit along with the host.
+    match connection.as_mut() {
+
+        Some(guest) => SendMessage(guest, &Message::End),
+        None => (),
+    }
+}
+
+// RunGuest Function:
+// Parameters:
+// - address: Address and port of the host.
+// Description: Joins a networked game. Displays every screen sent by the host and sends back commands when asked.
+fn RunGuest(address: &str) {
+
+    println!("Joining the game at {}.", address);
+
+    let mut host: Connection;
+    match Connection::Join(address) {
+
+        Ok(value) => host = value,
+        Err(error) => {
+
+            eprintln!("Error: Could not join the game. {}", error);
+            process::exit(1);
+        },
+    }
+
+    loop {
+
+        match host.Receive() {
+
+            Ok(Message::Screen(lines)) => ShowScreen(&lines),
+            Ok(Message::Prompt) => {
+
+                let user_input = ReadInput();
+                SendMessage(&mut host, &Message::Command(user_input));
+            },
+            Ok(Message::End) => return,
+            Ok(Message::Command(_)) => (),
+            Err(error) => {
+
+                eprintln!("Error: Lost connection to the host. {}", error);
+                process::exit(1);
+            },
+        }
+    }
+}
+
+// SendMessage Function:
+// Parameters:
+// - connection: Connection to the other process.
+// - message: The message to send.
+// Description: Sends a message. Exits the game if the other process can no longer be reached.
+fn SendMessage(connection: &mut Connection, message: &Message) {
+
+    match connection.Send(message) {
+
+        Ok(_) => (),
+        Err(error) => {
+
+            eprintln!("Error: Lost connection to the other player. {}", error);
+            process::exit(1);
+        },
+    }
+}
+
+// ReceiveCommand Function:
+// Parameters:
+// - guest: Connection to the guest.
+// Description: Asks the guest for a command and waits for it.
+// Return: The line typed by the guest.
+fn ReceiveCommand(guest: &mut Connection) -> String {
+
+    SendMessage(guest, &Message::Prompt);
+
+    loop {
+
+        match guest.Receive() {
+
+            Ok(Message::Command(user_input)) => return user_input,
+            Ok(_) => (),
+            Err(error) => {
+
+                eprintln!("Error: Lost connection to the guest. {}", error);
+                process::exit(1);
+            },
+        }
+    }
+}
+
+// ParseArguments Function:
+// Parameters:
+// - arguments: Command line arguments without the program name.
+// Description: Reads the command line flags used to launch the game.
+// Return: Options struct. Error message if a flag is not recognised.
+fn ParseArguments(arguments: Vec<String>) -> Result<Options, String> {
+
+    let mut options = Options {
+
+        mode: Mode::Computer(Difficulty::Easy),
+        host: None,
+        join: None,
+        load: None,
+        replay: None,
+        undo: UndoRule::Always,
+        seed: None,
+        config: GameConfig::default(),
+        board: None,
+        player_deck: None,
+        opponent_deck: None,
+        transition_metals: false,
+        scoring: None
+    };
+
+    let mut index = 0;
+    while index < arguments.len() {
+
+        match arguments[index].as_str() {
+
+            "--difficulty" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|name| Difficulty::FromName(name)) {
+
+                    Some(difficulty) => options.mode = Mode::Computer(difficulty),
+                    None => return Err(String::from("Error: --difficulty must be easy, medium, hard, or expert.")),
+                }
+            },
+            "--hotseat" => options.mode = Mode::Hotseat,
+            "--host" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|port| port.parse::<u16>().ok()) {
+
+                    Some(port) => options.host = Some(port),
+                    None => return Err(String::from("Error: --host must be followed by a port number.")),
+                }
+            },
+            "--join" => {
+
+                index += 1;
+                match arguments.get(index) {
+
+                    Some(address) => options.join = Some(address.clone()),
+                    None => return Err(String::from("Error: --join must be followed by an address. (Example: 127.0.0.1:7878)")),
+                }
+            },
+            "--load" => {
+
+                index += 1;
+                match arguments.get(index) {
+
+                    Some(path) => options.load = Some(path.clone()),
+                    None => return Err(String::from("Error: --load must be followed by a file.")),
+                }
+            },
+            "--undo" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|name| UndoRule::FromName(name)) {
+
+                    Some(rule) => options.undo = rule,
+                    None => return Err(String::from("Error: --undo must be off, turn, or always.")),
+                }
+            },
+            "--seed" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|seed| seed.parse::<u64>().ok()) {
+
+                    Some(seed) => options.seed = Some(seed),
+                    None => return Err(String::from("Error: --seed must be followed by a whole number.")),
+                }
+            },
+            "--board" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|size| GameConfig::ParseBoardSize(size)) {
+
+                    Some(size) => options.board = Some(size),
+                    None => return Err(format!("Error: --board must be a size from {0}x{0} to {1}x{1}. (Example: 8x8)", MIN_BOARD_SIZE, MAX_BOARD_SIZE)),
+                }
+            },
+            "--preset" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|name| GameConfig::FromPreset(name)) {
+
+                    Some(config) => options.config = config,
+                    None => return Err(String::from("Error: --preset must be standard, lunch, or tournament.")),
+                }
+            },
+            "--config" => {
+
+                index += 1;
+                match arguments.get(index) {
+
+                    Some(path) => match GameConfig::LoadFile(path) {
+
+                        Ok(config) => options.config = config,
+                        Err(error) => return Err(error.to_string()),
+                    },
+                    None => return Err(String::from("Error: --config must be followed by a file.")),
+                }
+            },
+            "--transition-metals" => options.transition_metals = true,
+            "--scoring" => {
+
+                index += 1;
+                match arguments.get(index).and_then(|name| ScoringMode::FromName(name)) {
+
+                    Some(scoring) => options.scoring = Some(scoring),
+                    None => return Err(String::from("Error: --scoring must be classic or polarity.")),
+                }
+            },
+            "--player-deck" | "--opponent-deck" => {
+
+                let flag = arguments[index].clone();
+                index += 1;
+
+                let list = match arguments.get(index) {
+
+                    Some(path) => match DeckList::LoadFile(path) {
+
+                        Ok(list) => list,
+                        Err(error) => return Err(error.to_string()),
+                    },
+                    None => return Err(format!("Error: {} must be followed by a file.", flag)),
+                };
+
+                if flag == "--player-deck" {
+
+                    options.player_deck = Some(list);
+                } else {
+
+                    options.opponent_deck = Some(list);
+                }
+            },
+            "--replay" => {
+
+                index += 1;
+                match arguments.get(index) {
+
+                    Some(path) => options.replay = Some(path.clone()),
+                    None => return Err(String::from("Error: --replay must be followed by a file.")),
+                }
+            },
+            flag => return Err(format!("Error: Unknown flag \"{}\".", flag)),
+        }
+
+        index += 1;
+    }
+
+    // A board size given on its own is used whichever order the flags were given in.
+    match options.board {
+
+        Some(size) => options.config.board_size = size,
+        None => (),
+    }
+
+    match options.player_deck.take() {
+
+        Some(list) => options.config.player_deck = list,
+        None => (),
+    }
+
+    match options.opponent_deck.take() {
+
+        Some(list) => options.config.opponent_deck = list,
+        None => (),
+    }
+
+    if options.transition_metals {
+
+        options.config.transition_metals = true;
+    }
+
+    match options.scoring {
+
+        Some(scoring) => options.config.scoring = scoring,
+        None => (),
+    }
+
+    // Deck lists given as flags are checked against the rules they are used with.
+    match options.config.Check() {
+
+        Ok(_) => (),
+        Err(message) => return Err(format!("Error: {}", message)),
+    }
+
+    // The host plays against the guest instead of the computer.
+    if options.host != None {
+
+        if options.join != None {
+
+            return Err(String::from("Error: --host and --join cannot be used together."));
+        }
+
+        options.mode = Mode::Network;
+    }
+
+    return Ok(options);
+}
+
+// MatchStart Function:
+// Parameters:
+// - user_input: The user input to be parsed.
+// - mode: Computer difficulty or hotseat. Changed if a difficulty or hotseat is given after start.
+// Description: Checks for the start command, which can be followed by the name of a difficulty or hotseat.
+// Return: True if the start command was used properly.
+fn MatchStart(user_input: &str, mode: &mut Mode) -> bool {
+
+    let input_fields = MatchCommand(user_input);
+    if (input_fields.0 != Some(String::from("start"))) || (input_fields.1.1 != None) {
+
+        return false;
+    }
+
+    match input_fields.1.0 {
+
+        Some(name) => {
+
+            // The guest stays connected between games, so a networked game cannot change modes.
+            if *mode == Mode::Network {
+
+                return false;
+            }
+
+            if name == "hotseat" {
+
+                *mode = Mode::Hotseat;
+                return true;
+            }
+
+            match Difficulty::FromName(&name) {
+
+                Some(value) => *mode = Mode::Computer(value),
+                None => return false,
+            }
+        },
+        None => (),
+    }
+
+    return true;
+}
+
+// ShowBonds Function:
+// Parameters:
+// - user_input: The bonds command and the tile to look at.
+// - game: The game to log the bonds within.
+// Description: Logs every bond of the atom on a tile, along with the tile of the atom on the other end.
+fn ShowBonds(user_input: &str, game: &mut Game) {
+
+    let input_fields = MatchCommand(user_input);
+    if (input_fields.1.0 == None) || (input_fields.1.1 != None) {
+
+        game.PushAction(String::from("Error: Invalid parameters."), true);
+        return;
+    }
+
+    let coords = CreateCoords(input_fields.1.0.unwrap());
+    if !coords.0 || !game.GetBoard().IsOnBoard(coords.1) {
+
+        game.PushAction(String::from("Error: Invalid parameters."), true);
+        return;
+    }
+
+    let bonds: Vec<String> = game.GetBoard().GetBonds(coords.1).iter()
+        .map(|bond| format!("a {} bond to {}", bond.GetOrderName(), TileName(bond.GetOther(coords.1))))
+        .collect();
+
+    if bonds.is_empty() {
+
+        game.PushAction(format!("{} has no bonds.", TileName(coords.1)), false);
+    }
+    else {
+
+        game.PushAction(format!("{} has {}.", TileName(coords.1), bonds.join(" and ")), false);
+    }
+}
+
+// SaveGame Function:
+// Parameters:
+// - user_input: The save command and the file to write.
+// - game: The game to save.
+// Description: Writes the game to a file. The result is logged within the game.
+fn SaveGame(user_input: &str, game: &mut Game) {
+
+    let input_fields = MatchCommand(user_input);
+    if (input_fields.1.0 == None) || (input_fields.1.1 != None) {
+
+        game.PushAction(String::from("Error: Invalid parameters."), true);
+        return;
+    }
+
+    let path = input_fields.1.0.unwrap();
+    match game.SaveFile(&path) {
+
+        Ok(_) => game.PushAction(format!("Game saved to {}.", path), false),
+        Err(error) => game.PushAction(error.to_string(), true),
+    }
+}
+
+// LoadGame Function:
+// Parameters:
+// - user_input: The load command and the file to read.
+// - game: The game to replace.
+// - mode: Who controls the opponent side of the loaded game.
+// Description: Replaces the game with one read from a file. Errors are logged within the current game.
+fn LoadGame(user_input: &str, game: &mut Game, mode: Mode) {
+
+    let input_fields = MatchCommand(user_input);
+    if (input_fields.1.0 == None) || (input_fields.1.1 != None) {
+
+        game.PushAction(String::from("Error: Invalid parameters."), true);
+        return;
+    }
+
+    let path = input_fields.1.0.unwrap();
+    match Game::LoadFile(&path) {
+
+        Ok(loaded) => {
+
+            *game = loaded;
+            game.PushAction(format!("Game loaded from {}.", path), false);
+            CheckLoadedMode(game, mode);
+        },
+        Err(error) => game.PushAction(error.to_string(), true),
+    }
+}
+
+// CheckLoadedMode Function:
+// Parameters:
+// - game: A game that was just loaded.
+// - mode: Who controls the opponent side now.
+// Description: Logs a warning if the game was saved in another mode, such as a hotseat game loaded without --hotseat.
+//   The game continues in the current mode.
+fn CheckLoadedMode(game: &mut Game, mode: Mode) {
+
+    let names = (game.GetSideName(Side::Player), game.GetSideName(Side::Opponent));
+    if names != mode.GetSideNames() {
+
+        game.PushAction(format!("Warning: The game was saved in another mode. It continues in {} mode.", mode.GetName()), true);
+    }
+}
+
+// RecordGame Function:
+// Parameters:
+// - user_input: The record command and the file to write.
+// - game: The game to write a replay of.
+// Description: Writes the replay of the game so far to a file. The result is logged within the game.
+fn RecordGame(user_input: &str, game: &mut Game) {
+
+    let input_fields = MatchCommand(user_input);
+    if (input_fields.1.0 == None) || (input_fields.1.1 != None) {
+
+        game.PushAction(String::from("Error: Invalid parameters."), true);
+        return;
+    }
+
+    let path = input_fields.1.0.unwrap();
+    match game.GetReplay().SaveFile(&path) {
+
+        Ok(_) => game.PushAction(format!("Replay saved to {}.", path), false),
+        Err(error) => game.PushAction(error.to_string(), true),
+    }
+}
+
+// RunReplay Function:
+// Parameters:
+// - path: The replay file to view.
+// Description: Plays a replay back one action at a time. The board, log, and the hand of the side whose turn it is
+//   are shown for every step, and the viewer can step forward and back.
+fn RunReplay(path: &str) {
+
+    let steps: Vec<Game>;
+    match Replay::LoadFile(path).map_err(|error| error.to_string()).and_then(|replay| replay.Steps()) {
+
+        Ok(value) => steps = value,
+        Err(message) => {
+
+            eprintln!("{}", message);
+            process::exit(1);
+        },
+    }
+
+    let mut index: usize = 0;
+    let mut message = String::new();
+    loop {
+
+        // Refresh the screen and get user input.
+        //-----------------------------------------------------------------------
+        let game = &steps[index];
+        let mut screen = RenderScreen(game, game.GetCurrentSide());
+        screen.push(format!("Replay step {} of {}. Press enter or type \"next\" to step forward. Type \"back\", \"first\", \"last\", or \"quit\".", index, steps.len() - 1));
+        screen.push(message.clone());
+        ShowScreen(&screen);
+
+        let user_input = ReadInput();
+        //-----------------------------------------------------------------------
+
+        message = String::new();
+        match user_input.split_whitespace().next() {
+
+            None => index = ReplayStep::Next.Move(index, steps.len()),
+            Some("quit") => return,
+            Some(command) => match ReplayStep::FromName(command) {
+
+                Some(step) => index = step.Move(index, steps.len()),
+                None => message = String::from("Error: Command not recognised."),
+            },
+        }
+    }
+}
+
+// ParseAction Function:
+// Parameters:
+// - user_input: The user input to be parsed.
+// Description: Converts a command used during a turn into an action for the game.
+// Return: The action. Error message if the command or its parameters are invalid.
+fn ParseAction(user_input: &str) -> Result<Action, String> {
+
+    let input_fields = MatchCommand(user_input);
+    let invalid_parameters = String::from("Error: Invalid parameters.");
+
+    if input_fields.0 == Some(String::from("restrict")) {
+
+        // Check that user used the correct number of imput fields.
+        if (input_fields.1.0 != None) && (input_fields.1.1 != None) && (input_fields.1.2 == None) {
+
+            // Check that coords can be created for the spaces provided by user.
+            let coords_1 = CreateCoords(input_fields.1.0.unwrap());
+            let coords_2 = CreateCoords(input_fields.1.1.unwrap());
+            if coords_1.0 && coords_2.0 {
+
+                return Ok(Action::Restrict { a: coords_1.1, b: coords_2.1 });
+            }
+        }
+
+        return Err(invalid_parameters);
+    } else if input_fields.0 == Some(String::from("destroy")) {
+
+        // Check only one parameter exists for destroy function.
+        if (input_fields.1.0 != None) && (input_fields.1.1 == None) {
+
+            let coords = CreateCoords(input_fields.1.0.unwrap());
+            if coords.0 {
+
+                return Ok(Action::Destroy { tile: coords.1 });
+            }
+        }
+
+        return Err(invalid_parameters);
+    } else if input_fields.0 == Some(String::from("evolve")) {
+
+        // Check that the fields for evolve are satisfied.
+        if (input_fields.1.0 != None) && (input_fields.1.1 == None) {
+
+            return Ok(Action::Evolve { element: input_fields.1.0.unwrap() });
+        }
+
+        return Err(invalid_parameters);
+    } else if input_fields.0 == Some(String::from("pass")) {
+
+        return Ok(Action::Pass);
+    } else if input_fields.0 == Some(String::from("play")) {
+
+        // The bond order is given with --order and can come anywhere after the command.
+        let (user_input, order) = match SplitOrder(user_input) {
+
+            Some(split) => split,
+            None => return Err(invalid_parameters),
+        };
+        let input_fields = MatchCommand(&user_input);
+
+        // Verify fields used for play command. The third field is an optional oxidation state.
+        if (input_fields.1.0 != None) && (input_fields.1.1 != None) {
+
+            let oxidation: Option<u16> = match input_fields.1.2 {
+
+                Some(state) => match state.parse::<u16>() {
+
+                    Ok(state) => Some(state),
+                    Err(_) => return Err(invalid_parameters),
+                },
+                None => None,
+            };
+
+            // Numeric coordinates, not tile coordinates.
+            let coords = CreateCoords(input_fields.1.1.unwrap());
+            if coords.0 {
+
+                return Ok(Action::Play { element: input_fields.1.0.unwrap(), tile: coords.1, oxidation, order });
             }
         }
+
+        return Err(invalid_parameters);
+    }
+
+    return Err(String::from("Error: Invalid command."));
+}
+
+// RenderScreen Function:
+// Parameters:
+// - game: The game to display.
+// - side: The side whose hand is shown.
+// Description: Draws the score, board, and the hand of one side.
+// Return: The lines of the screen. Prompts are pushed on after.
+fn RenderScreen(game: &Game, side: Side) -> Vec<String> {
+
+    let mut screen: Vec<String> = Vec::new();
+
+    DrawScore(&mut screen, game.GetBoard(), &game.GetSideName(Side::Player), game.GetScore(Side::Player), &game.GetSideName(Side::Opponent), game.GetScore(Side::Opponent));
+    DrawGameBoard(&mut screen, game.GetBoard(), game.GetLog());
+    DrawHand(&mut screen, game.GetBoard(), game.GetDeck(side), game.GetAbilities(side));
+
+    return screen;
+}
+
+// ShowScreen Function:
+// Parameters:
+// - screen: The lines to display.
+// Description: Clears the terminal and prints a screen.
+fn ShowScreen(screen: &[String]) {
+
+    println!("{}", clear::All);
+
+    for line in screen {
+
+        println!("{}", line);
     }
 }
 
-// PrintScore Function:
+// PassScreen Function:
+// Parameters:
+// - game: The game to display.
+// - side: The side receiving the keyboard.
+// Description: Prints the board without a hand and waits for enter, so that neither hand is seen by the other player.
+fn PassScreen(game: &Game, side: Side) {
+
+    ShowScreen(&DrawPassScreen(game, side));
+
+    ReadInput();
+}
+
+// DrawPassScreen Function:
+// Parameters:
+// - game: The game to display.
+// - side: The side receiving the keyboard.
+// Description: Draws the score, the board, and an empty hand, followed by the prompt to pass the keyboard.
+// Return: The lines of the screen.
+fn DrawPassScreen(game: &Game, side: Side) -> Vec<String> {
+
+    let mut screen: Vec<String> = Vec::new();
+    DrawScore(&mut screen, game.GetBoard(), &game.GetSideName(Side::Player), game.GetScore(Side::Player), &game.GetSideName(Side::Opponent), game.GetScore(Side::Opponent));
+    DrawGameBoard(&mut screen, game.GetBoard(), game.GetLog());
+    DrawEmptyHand(&mut screen, game.GetBoard());
+
+    screen.push(format!("Pass the keyboard to {}. Press enter when ready.", game.GetSideName(side)));
+    return screen;
+}
+
+// DrawScore Function:
 // Parameters:
+// - screen: The lines of the screen being drawn.
+// - game_board: Class representing the game board. Used for the column names.
+// - player_name: Name shown for the player side.
 // - player: Player's score in the game.
-// - player: Computer's score in the game.
-// Description: Prints the portion of the game board that contains score.
-fn PrintScore(player: u16, computer: u16) {
+// - computer_name: Name shown for the opponent side.
+// - computer: Computer's score in the game.
+// Description: Draws the portion of the game board that contains score.
+fn DrawScore(screen: &mut Vec<String>, game_board: &GameBoard, player_name: &str, player: u16, computer_name: &str, computer: u16) {
 
     // Need to add some spaces so that the text does not shift when there is a smaller number.
     let spaces: String;
@@ -452,143 +1168,231 @@ fn PrintScore(player: u16, computer: u16) {
         spaces = String::from("");
     }
 
-    println!("----                                               ----  | Player Score: {}{}       Computer Score: {}", player, spaces, computer);
-    println!("|H |       A   B   C   D   E   F                   |He|  -------------------------------------------------------")
+    let gap = BoardGap(game_board);
+    screen.push(format!("----   {}               ----  | {} Score: {}{}       {} Score: {}", " ".repeat(gap), player_name, player, spaces, computer_name, computer));
+
+    // Column names line up with the symbols inside of the tiles.
+    let mut columns = format!("  {}", " ".repeat(LabelWidth(game_board)));
+    for i in 0..game_board.GetWidth() {
+
+        columns.push_str(&format!(" {}  ", (b'A' + i as u8) as char));
+    }
+
+    screen.push(format!("|H |   {:<gap$}               |He|  {}", columns, "-".repeat(55 + gap - TABLE_GAP), gap = gap));
 }
 
-// PrintGameBoard Function:
+// DrawGameBoard Function:
 // Parameters:
+// - screen: The lines of the screen being drawn.
 // - game_board: Class representing the game board.
 // - action_log: Class holding logged events.
-// Description: Prints the portion of the game board that board tiles and actions.
-fn PrintGameBoard(game_board: &mut GameBoard, action_log: &ActionLog) {
+// Description: Draws the portion of the game board that board tiles and actions.
+fn DrawGameBoard(screen: &mut Vec<String>, game_board: &GameBoard, action_log: &ActionLog) {
 
-    // Row 1.
-    print!("|1 |     1");
-    for i in 0..6 {
-        game_board.PrintTile((i, 0));
-    }
-    print!("                 |2 |  | Action Log:\n");
+    let gap = BoardGap(game_board);
+    let label_width = LabelWidth(game_board);
 
-    // Row 2.
-    print!("-------  2");
-    for i in 0..6 {
-        game_board.PrintTile((i, 1));
-    }
-    print!("  -------------------  | ");
-    action_log.PrintIndex(0);
-    print!("\n");
+    // Rows of tiles are drawn on every other line, with the bonds between rows drawn on the lines between them.
+    let board_lines = (2 * game_board.GetHeight()) - 1;
+    for line in 0..board_lines.max(TABLE_ROWS.len()) {
+
+        let (left, right) = match TABLE_ROWS.get(line) {
+
+            Some(table_row) => *table_row,
+            None => TABLE_EMPTY_ROW,
+        };
+
+        // Tiles contain color codes, so the space after the board is worked out from the number of tiles.
+        let padding = gap - (2 + label_width + 4 * game_board.GetWidth());
+        let middle: String;
+        if line >= board_lines {
+
+            middle = " ".repeat(gap);
+        } else if line % 2 == 0 {
+
+            let row = line / 2;
+            middle = format!("  {:>width$}{}{}", row + 1, DrawRow(game_board, row), " ".repeat(padding), width = label_width);
+        } else {
+
+            middle = format!("  {}{}{}", " ".repeat(label_width), DrawBondRow(game_board, line / 2), " ".repeat(padding));
+        }
+
+        // The action log is drawn to the right of the table.
+        let log_line: String;
+        if line == 0 {
+
+            log_line = String::from("| Action Log:");
+        } else if line <= 5 {
+
+            log_line = format!("| {}", action_log.FormatIndex(line - 1));
+        } else {
+
+            log_line = String::from("|");
+        }
 
-    // Row 3.
-    print!("|Li|Be|  3");
-    for i in 0..6 {
-        game_board.PrintTile((i, 2));
+        screen.push(format!("{}{}{}  {}", left, middle, right, log_line));
     }
-    print!("  |B |C |N |O |F |Ne|  | ");
-    action_log.PrintIndex(1);
-    print!("\n");
 
-    // Row 4.
-    print!("|3 |4 |  4");
-    for i in 0..6 {
-        game_board.PrintTile((i, 3));
+    screen.push(format!("|11|12|{}|13|14|15|16|17|18|  {}", " ".repeat(gap), "-".repeat(55 + gap - TABLE_GAP)));
+}
+
+// DrawRow Function:
+// Parameters:
+// - game_board: Class representing the game board.
+// - row: The row of tiles to draw.
+// Description: Draws every tile within one row of the board.
+// Return: The tiles of the row as a string.
+fn DrawRow(game_board: &GameBoard, row: usize) -> String {
+
+    let mut line = String::new();
+    for i in 0..game_board.GetWidth() {
+
+        line.push_str(&game_board.FormatTile((i, row)));
     }
-    print!("  |5 |6 |7 |8 |9 |10|  | ");
-    action_log.PrintIndex(2);
-    print!("\n");
 
-    // Row 5.
-    print!("-------  5");
-    for i in 0..6 {
-        game_board.PrintTile((i, 4));
+    return line;
+}
+
+// DrawBondRow Function:
+// Parameters:
+// - game_board: Class representing the game board.
+// - row: The row of tiles above the line being drawn.
+// Description: Draws the bonds between one row of tiles and the row below it. Single, double, and triple bonds are
+//   drawn as |, ‖, and ⦀ under the symbol of the upper atom.
+// Return: The bonds of the row as a string, four characters per tile.
+fn DrawBondRow(game_board: &GameBoard, row: usize) -> String {
+
+    let mut line = String::new();
+    for i in 0..game_board.GetWidth() {
+
+        match game_board.GetBond((i, row), (i, row + 1)) {
+
+            Some(bond) => {
+
+                match bond.order {
+
+                    1 => line.push_str(" |  "),
+                    2 => line.push_str(" ‖  "),
+                    _ => line.push_str(" ⦀  "),
+                }
+            },
+            None => line.push_str("    "),
+        }
     }
-    print!("  -------------------  | ");
-    action_log.PrintIndex(3);
-    print!("\n");
 
-    // Row 6.
-    print!("|Na|Mg|  6");
-    for i in 0..6 {
-        game_board.PrintTile((i, 5));
+    return line;
+}
+
+// BoardGap Function:
+// Parameters:
+// - game_board: Class representing the game board.
+// Description: Returns the width of the gap in the periodic table that the board is drawn in.
+fn BoardGap(game_board: &GameBoard) -> usize {
+
+    // Two spaces, the row label, four characters per tile, and two spaces.
+    let needed = 2 + LabelWidth(game_board) + (4 * game_board.GetWidth()) + 2;
+    return needed.max(TABLE_GAP);
+}
+
+// LabelWidth Function:
+// Parameters:
+// - game_board: Class representing the game board.
+// Description: Returns the number of characters needed for the largest row number.
+fn LabelWidth(game_board: &GameBoard) -> usize {
+
+    return game_board.GetHeight().to_string().len();
+}
+
+// SplitRow Function:
+// Parameters:
+// - left: Part of a row of the periodic table from the left edge to the end of the transition metals.
+// - right: The rest of the row without its first border.
+// - extra: Number of characters the table was stretched by.
+// Description: Joins the two parts of a row so that they line up with a stretched table.
+fn SplitRow(left: &str, right: &str, extra: usize) -> String {
+
+    if extra == 0 {
+
+        return format!("{}{}", left, right);
     }
-    print!("  |Al|Si|P |S |Cl|Ar|  | ");
-    action_log.PrintIndex(4);
-    print!("\n");
 
-    println!("|11|12|                             |13|14|15|16|17|18|  -------------------------------------------------------")
+    return format!("{}{}|{}", left, " ".repeat(extra - 1), right);
 }
 
-// PrintHand Function:
+// DrawHand Function:
 // Parameters:
+// - screen: The lines of the screen being drawn.
+// - game_board: Class representing the game board. Used for the width of the table.
 // - hand: Class containing the player's hand.
-// - evolve, destroy, and restrict: Variables holding the counters for abities.
-// Description: Prints the part of the game board which contains player hand and ability counters.
-fn PrintHand(hand: &mut CardDeck, evolve: u8, destroy: u8, restrict: u8) {
+// - abilities: Struct holding the counters for abities.
+// Description: Draws the part of the game board which contains player hand and ability counters.
+fn DrawHand(screen: &mut Vec<String>, game_board: &GameBoard, hand: &CardDeck, abilities: &Abilities) {
 
-    println!("-------------------------------------------------------  | Player Hand:            Remaining Abilities:");
+    let extra = BoardGap(game_board) - TABLE_GAP;
 
-    print!("|K |Ca|Sc|Ti|V |Cr|Mn|Fe|Co|Ni|Cu|Zn|Ga|Ge|As|Se|Br|Kr|  | ");
-    hand.PrintUpperHand();
-    print!("        Destroy x {}\n", destroy);
+    screen.push(format!("{}  | Player Hand:            Remaining Abilities:", "-".repeat(55 + extra)));
+    screen.push(format!("{}  | {}        Destroy x {}", SplitRow("|K |Ca|Sc|Ti|V |Cr|Mn|Fe|Co|Ni|Cu|Zn|", "Ga|Ge|As|Se|Br|Kr|", extra), hand.FormatUpperHand(), abilities.destroy));
+    screen.push(format!("{}  | {}        Evolve x {}", SplitRow("|19|20|21|22|23|24|25|26|27|28|29|30|", "31|32|33|34|35|36|", extra), hand.FormatLowerHand(), abilities.evolve));
+    screen.push(format!("{}  |                         Restrict x {}", "-".repeat(55 + extra), abilities.restrict));
+    screen.push("_".repeat(112 + extra));
+}
 
-    print!("|19|20|21|22|23|24|25|26|27|28|29|30|31|32|33|34|35|36|  | ");
-    hand.PrintLowerHand();
-    print!("        Evolve x {}\n", evolve);
+// DrawEmptyHand Function:
+// Parameters:
+// - screen: The lines of the screen being drawn.
+// - game_board: Class representing the game board. Used for the width of the table.
+// Description: Draws a blank hand for when the game is first launced.
+fn DrawEmptyHand(screen: &mut Vec<String>, game_board: &GameBoard) {
+
+    let extra = BoardGap(game_board) - TABLE_GAP;
+
+    screen.push(format!("{}  | Player Hand:            Remaining Abilities:", "-".repeat(55 + extra)));
+    screen.push(format!("{}  |", SplitRow("|K |Ca|Sc|Ti|V |Cr|Mn|Fe|Co|Ni|Cu|Zn|", "Ga|Ge|As|Se|Br|Kr|", extra)));
+    screen.push(format!("{}  |", SplitRow("|19|20|21|22|23|24|25|26|27|28|29|30|", "31|32|33|34|35|36|", extra)));
+    screen.push(format!("{}  |", "-".repeat(55 + extra)));
+    screen.push("_".repeat(112 + extra));
+}
 
-    print!("-------------------------------------------------------  | ");
-    print!("                        Restrict x {}\n", restrict);
+// ReadInput Function:
+// Description: Reads a line of user input. Exits the game if the input has been closed.
+// Return: The line typed by the user.
+fn ReadInput() -> String {
 
-    println!("________________________________________________________________________________________________________________");
-}
+    let mut user_input = String::new();
+    match stdin().read_line(&mut user_input) {
 
-// PrintEmptyHand Function:
-// Description: Prints a blank hand for when the game is first launced.
-fn PrintEmptyHand() {
+        Ok(0) | Err(_) => std::process::exit(0),
+        Ok(_) => (),
+    }
 
-    println!("-------------------------------------------------------  | Player Hand:            Remaining Abilities:");
-    println!("|K |Ca|Sc|Ti|V |Cr|Mn|Fe|Co|Ni|Cu|Zn|Ga|Ge|As|Se|Br|Kr|  |");
-    println!("|19|20|21|22|23|24|25|26|27|28|29|30|31|32|33|34|35|36|  |");
-    println!("-------------------------------------------------------  |");
-    println!("________________________________________________________________________________________________________________");
+    return user_input;
 }
 
 // CreateCoords Function:
 // Parameters:
-// - tile: String symbol for a tile supplied by the user.
-// Description: Converts the string coordinates given by the user to a numeric representation.
+// - tile: String symbol for a tile supplied by the user. A column letter followed by a row number. (Example: B10)
+// Description: Converts the string coordinates given by the user to a numeric representation. Coordinates outside
+//   of the board are still created, so that the board can report them.
 // Return: Tuple containing boolean flag and a pair of coordinates.
 fn CreateCoords(tile: String) -> (bool, (usize, usize)) {
 
-    // Coordinate length is two.
-    if tile.len() > 2 {
-
-        return (false, (0, 0));
-    }
+    let mut characters = tile.chars();
 
     let x: usize;
     let y: usize;
 
-    // Convert the first index to array coordinates,
-    match tile.chars().nth(0) {
+    // Convert the column letter to array coordinates.
+    match characters.next() {
 
-        Some('A') | Some('a') => x = 0,
-        Some('B') | Some('b') => x = 1,
-        Some('C') | Some('c') => x = 2,
-        Some('D') | Some('d') => x = 3,
-        Some('E') | Some('e') => x = 4,
-        Some('F') | Some('f') => x = 5,
+        Some(letter) if letter.is_ascii_alphabetic() => x = (letter.to_ascii_uppercase() as u8 - b'A') as usize,
         _ => return (false, (0, 0)),
     };
 
-    // Convert the second index to array coordinates.
-    match tile.chars().nth(1) {
+    // Convert the row number to array coordinates. Rows are numbered from one.
+    let row = characters.as_str();
+    match row.parse::<usize>() {
 
-        Some('1') => y = 0,
-        Some('2') => y = 1,
-        Some('3') => y = 2,
-        Some('4') => y = 3,
-        Some('5') => y = 4,
-        Some('6') => y = 5,
+        Ok(number) if (number > 0) && row.chars().all(|digit| digit.is_ascii_digit()) => y = number - 1,
         _ => return (false, (0, 0)),
     };
 
@@ -596,22 +1400,52 @@ fn CreateCoords(tile: String) -> (bool, (usize, usize)) {
     return (true, (x, y));
 }
 
+// SplitOrder Function:
+// Parameters:
+// - user_input: A play command that may hold a bond order. (Example: play O B3 --order 1)
+// Description: Takes the bond order out of a play command.
+// Return: The command without the bond order, and the bond order if one was given. None if --order is not followed
+//   by a number.
+fn SplitOrder(user_input: &str) -> Option<(String, Option<u16>)> {
+
+    let mut words: Vec<&str> = user_input.split_whitespace().collect();
+    match words.iter().position(|word| *word == "--order") {
+
+        Some(index) => {
+
+            let order = words.get(index + 1)?.parse::<u16>().ok()?;
+            words.drain(index..(index + 2));
+            return Some((words.join(" "), Some(order)));
+        },
+        None => return Some((String::from(user_input), None)),
+    }
+}
+
+// TileName Function:
+// Parameters:
+// - coords: Array coordinates of a tile.
+// Description: Names a tile the way the user types it. The reverse of CreateCoords. (Example: (1, 3) is B4)
+fn TileName(coords: (usize, usize)) -> String {
+
+    return format!("{}{}", (b'A' + coords.0 as u8) as char, coords.1 + 1);
+}
+
 // MatchCommand Function:
 // Parameters:
 // - user_input: The user input to be parsed.
 // Description: Sorts the prompt written by user into important elements.
 // Return: (command, (parameter, parameter, parameter)). Inside of option because they may not exist.
-fn MatchCommand(user_input: &mut String) -> (Option<String>, (Option<String>, Option<String>, Option<String>)) {
+fn MatchCommand(user_input: &str) -> (Option<String>, (Option<String>, Option<String>, Option<String>)) {
+
+    // Separate elements of input.
+    let command_vector: Vec<&str> = user_input.split_whitespace().collect();
 
     // Check that enter was not accidentally pressed.
-    if *user_input == String::from("\n") {
+    if command_vector.is_empty() {
 
         return (None, (None, None, None));
     }
 
-    // Separate elements of input.
-    let command_vector: Vec<&str> = user_input.split_whitespace().collect();
-
     let command_keyword: Option<String> = VerifyCommand(command_vector[0]);
 
     let mut parameter_1: Option<String> = None;
@@ -653,6 +1487,12 @@ fn VerifyCommand(command: &str) -> Option<String> {
         "destroy" => return Some(String::from(command)),
         "evolve" => return Some(String::from(command)),
         "pass" => return Some(String::from(command)),
+        "save" => return Some(String::from(command)),
+        "load" => return Some(String::from(command)),
+        "record" => return Some(String::from(command)),
+        "undo" => return Some(String::from(command)),
+        "redo" => return Some(String::from(command)),
+        "bonds" => return Some(String::from(command)),
         _ => return None,
     }
 }
\ No newline at end of file
@@ -0,0 +1,176 @@
+// Std modules.
+use std::io;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+#[cfg(test)]
+mod tests;
+
+/*
+Line Protocol:
+- Description: The host owns the game and the guest only displays it. Every message is one line of text. A screen
+  is sent as a header line with the number of lines that follow, so that lines of the screen are never mistaken
+  for messages.
+- Host to guest:
+  SCREEN <count>   Followed by <count> lines to display in place of the current screen.
+  PROMPT           The guest should read one line of input and send it back as a command.
+  END              The match is over and the guest should exit.
+- Guest to host:
+  COMMAND <text>   A line typed by the guest. Uses the same commands as local play (play, destroy, restrict,
+                   evolve, pass).
+*/
+
+// Enum for every message that can be sent over a connection.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Message {
+    Screen(Vec<String>),
+    Prompt,
+    End,
+    Command(String),
+}
+
+// Connection to the other process of a networked match.
+pub struct Connection {
+
+    reader: BufReader<TcpStream>,
+    writer: TcpStream
+}
+
+impl Connection {
+
+    // Host Function:
+    // Parameters:
+    // - port: The port to listen on.
+    // Description: Waits for one guest to join on the given port.
+    // Return: Connection to the guest.
+    pub fn Host(port: u16) -> io::Result<Connection> {
+
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        let (stream, _) = listener.accept()?;
+
+        return Connection::FromStream(stream);
+    }
+
+    // Join Function:
+    // Parameters:
+    // - address: Address and port of the host. (Example: 127.0.0.1:7878)
+    // Description: Connects to a host which is waiting for a guest.
+    // Return: Connection to the host.
+    pub fn Join(address: &str) -> io::Result<Connection> {
+
+        let stream = TcpStream::connect(address)?;
+
+        return Connection::FromStream(stream);
+    }
+
+    // Send Function:
+    // Parameters:
+    // - message: The message to send.
+    // Description: Writes a message to the other process.
+    pub fn Send(&mut self, message: &Message) -> io::Result<()> {
+
+        let mut text = String::new();
+        match message {
+
+            Message::Screen(lines) => {
+
+                text.push_str(&format!("SCREEN {}\n", lines.len()));
+                for line in lines {
+
+                    text.push_str(&Connection::SingleLine(line));
+                    text.push('\n');
+                }
+            },
+            Message::Prompt => text.push_str("PROMPT\n"),
+            Message::End => text.push_str("END\n"),
+            Message::Command(command) => text.push_str(&format!("COMMAND {}\n", Connection::SingleLine(command))),
+        }
+
+        self.writer.write_all(text.as_bytes())?;
+        return self.writer.flush();
+    }
+
+    // Receive Function:
+    // Description: Waits for the next message from the other process.
+    // Return: The message. Error if the connection was closed or the message is not part of the protocol.
+    pub fn Receive(&mut self) -> io::Result<Message> {
+
+        let line = self.ReadLine()?;
+        let (keyword, rest) = match line.split_once(' ') {
+
+            Some((keyword, rest)) => (keyword, rest),
+            None => (line.as_str(), ""),
+        };
+
+        match keyword {
+
+            "SCREEN" => {
+
+                let count: usize = rest.parse().map_err(|_| Connection::InvalidMessage(&line))?;
+                let mut lines: Vec<String> = Vec::new();
+                for _ in 0..count {
+
+                    lines.push(self.ReadLine()?);
+                }
+
+                return Ok(Message::Screen(lines));
+            },
+            "PROMPT" => return Ok(Message::Prompt),
+            "END" => return Ok(Message::End),
+            "COMMAND" => return Ok(Message::Command(String::from(rest))),
+            _ => return Err(Connection::InvalidMessage(&line)),
+        }
+    }
+
+    //-----------------------------------------------------------------------------------------------------------------------------
+    // Helper Functions
+    //-----------------------------------------------------------------------------------------------------------------------------
+
+    // FromStream Function:
+    // Parameters:
+    // - stream: A connected TCP stream.
+    // Description: Splits a stream into a buffered reader and a writer.
+    fn FromStream(stream: TcpStream) -> io::Result<Connection> {
+
+        // Messages are small, so send them as soon as they are written.
+        stream.set_nodelay(true)?;
+
+        return Ok(Connection {
+
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream
+        });
+    }
+
+    // ReadLine Function:
+    // Description: Reads one line without its line ending.
+    // Return: The line. Error if the other process closed the connection.
+    fn ReadLine(&mut self) -> io::Result<String> {
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Connection closed."));
+        }
+
+        return Ok(String::from(line.trim_end_matches(['\r', '\n'])));
+    }
+
+    // SingleLine Function:
+    // Parameters:
+    // - text: Text that will be sent as one line.
+    // Description: Removes line breaks so the text cannot be split into more than one message.
+    fn SingleLine(text: &str) -> String {
+
+        return text.replace(['\r', '\n'], " ");
+    }
+
+    // InvalidMessage Function:
+    // Parameters:
+    // - line: The line that could not be read.
+    // Description: Creates the error returned for a line that is not part of the protocol.
+    fn InvalidMessage(line: &str) -> io::Error {
+
+        return io::Error::new(io::ErrorKind::InvalidData, format!("Unknown message \"{}\".", line));
+    }
+}
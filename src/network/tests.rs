@@ -0,0 +1,74 @@
+// Std modules.
+use std::io::{ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+// My modules.
+use super::{Connection, Message};
+
+// Connect Function:
+// Description: Connects a host and a guest over localhost on a port chosen by the system.
+// Return: The host's connection followed by the guest's connection.
+fn Connect() -> (Connection, Connection) {
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let guest = thread::spawn(move || Connection::Join(&address).unwrap());
+    let (stream, _) = listener.accept().unwrap();
+    let host = Connection::FromStream(stream).unwrap();
+
+    return (host, guest.join().unwrap());
+}
+
+#[test]
+fn MessagesArriveAsTheyWereSent() {
+
+    let (mut host, mut guest) = Connect();
+
+    // Lines of a screen are read by count, so a line that looks like a message stays part of the screen.
+    let screen = vec![String::from("Score: 12"), String::from("PROMPT"), String::new(), String::from("END")];
+    host.Send(&Message::Screen(screen.clone())).unwrap();
+    host.Send(&Message::Prompt).unwrap();
+    host.Send(&Message::End).unwrap();
+
+    assert_eq!(guest.Receive().unwrap(), Message::Screen(screen));
+    assert_eq!(guest.Receive().unwrap(), Message::Prompt);
+    assert_eq!(guest.Receive().unwrap(), Message::End);
+
+    guest.Send(&Message::Command(String::from("play C A1"))).unwrap();
+    assert_eq!(host.Receive().unwrap(), Message::Command(String::from("play C A1")));
+}
+
+#[test]
+fn LineBreaksCannotSplitAMessage() {
+
+    let (mut host, mut guest) = Connect();
+
+    host.Send(&Message::Screen(vec![String::from("one\ntwo")])).unwrap();
+    guest.Send(&Message::Command(String::from("pass\nEND"))).unwrap();
+
+    assert_eq!(guest.Receive().unwrap(), Message::Screen(vec![String::from("one two")]));
+    assert_eq!(host.Receive().unwrap(), Message::Command(String::from("pass END")));
+}
+
+#[test]
+fn BadMessagesAreErrors() {
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // The other process writes lines that are not part of the protocol, then closes the connection.
+    let writer = thread::spawn(move || {
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(b"SCREEN two\nHELLO\nSCREEN 2\nonly one line\n").unwrap();
+    });
+    let (stream, _) = listener.accept().unwrap();
+    let mut host = Connection::FromStream(stream).unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(host.Receive().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(host.Receive().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(host.Receive().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,280 @@
+// Std modules.
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/*
+Save File Format:
+- Description: A save file is plain text with one record per line. The first line is a header naming the kind of
+  file, such as a saved game or a replay. Each record starts with a key followed by its fields, all separated by
+  tabs. Tabs are used instead of spaces because atom symbols such as "H " contain spaces.
+- Records are written and read in a fixed order, so every struct reads back exactly the records it wrote.
+- Fields:
+  Numbers and booleans are written as text. (Example: 12, true)
+  Coordinates and colors are written with commas. (Example: 3,4 or 255,255,255)
+  Optional fields are written as "-" when they hold nothing.
+*/
+
+// Enum describing why a save file could not be used.
+// - Io: The file could not be read or written.
+// - Format: The file was read but a line could not be understood.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SaveError {
+    Io(String),
+    Format { line: usize, message: String },
+}
+
+// The error messages shown within the action log.
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        match self {
+
+            SaveError::Io(message) => return write!(f, "Error: {}", message),
+            SaveError::Format { line, message } => return write!(f, "Error: Line {} of the file: {}", line, message),
+        }
+    }
+}
+
+// SaveField Trait:
+// Description: A value that can be written as a single field of a record.
+pub trait SaveField: Sized {
+
+    // ToField Function:
+    // Description: Converts the value to text.
+    fn ToField(&self) -> String;
+
+    // FromField Function:
+    // Parameters:
+    // - field: The text of the field.
+    // Description: Converts text back into a value.
+    // Return: None if the text does not hold this type of value.
+    fn FromField(field: &str) -> Option<Self>;
+}
+
+// Numbers, booleans, and strings use their normal text form.
+macro_rules! PlainField {
+    ($($kind:ty),*) => {
+        $(
+            impl SaveField for $kind {
+
+                fn ToField(&self) -> String {
+
+                    return self.to_string();
+                }
+
+                fn FromField(field: &str) -> Option<Self> {
+
+                    return field.parse().ok();
+                }
+            }
+        )*
+    };
+}
+
+PlainField!(u8, u16, u64, usize, bool, String);
+
+// Coordinates.
+impl SaveField for (usize, usize) {
+
+    fn ToField(&self) -> String {
+
+        return format!("{},{}", self.0, self.1);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        let (x, y) = field.split_once(',')?;
+        return Some((x.parse().ok()?, y.parse().ok()?));
+    }
+}
+
+// Colors.
+impl SaveField for (u8, u8, u8) {
+
+    fn ToField(&self) -> String {
+
+        return format!("{},{},{}", self.0, self.1, self.2);
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        let parts: Vec<&str> = field.split(',').collect();
+        if parts.len() != 3 {
+
+            return None;
+        }
+
+        return Some((parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?));
+    }
+}
+
+// Optional fields.
+impl<T: SaveField> SaveField for Option<T> {
+
+    fn ToField(&self) -> String {
+
+        match self {
+
+            Some(value) => return value.ToField(),
+            None => return String::from("-"),
+        }
+    }
+
+    fn FromField(field: &str) -> Option<Self> {
+
+        if field == "-" {
+
+            return Some(None);
+        }
+
+        return Some(Some(T::FromField(field)?));
+    }
+}
+
+// Collects the records of a save file.
+pub struct SaveWriter {
+
+    lines: Vec<String>
+}
+
+impl SaveWriter {
+
+    // New Function:
+    // Parameters:
+    // - header: First line of the file.
+    // Description: Starts a file with no records.
+    pub fn New(header: &str) -> SaveWriter {
+
+        return SaveWriter { lines: vec![String::from(header)] };
+    }
+
+    // Write Function:
+    // Parameters:
+    // - key: Name of the record.
+    // - fields: The fields of the record in order.
+    // Description: Adds a record to the end of the file. Tabs and line breaks are removed from fields.
+    pub fn Write(&mut self, key: &str, fields: &[String]) {
+
+        let mut line = String::from(key);
+        for field in fields {
+
+            line.push('\t');
+            line.push_str(&field.replace(['\t', '\r', '\n'], " "));
+        }
+
+        self.lines.push(line);
+    }
+
+    // Finish Function:
+    // Description: Joins every record into the text of the file.
+    pub fn Finish(self) -> String {
+
+        return self.lines.join("\n") + "\n";
+    }
+}
+
+// One record read from a save file.
+pub struct SaveRecord {
+
+    line: usize,
+    fields: Vec<String>
+}
+
+impl SaveRecord {
+
+    // Get Function:
+    // Parameters:
+    // - index: Position of the field after the key.
+    // Description: Converts a field of the record.
+    // Return: The value. Error if the field is missing or holds the wrong type of value.
+    pub fn Get<T: SaveField>(&self, index: usize) -> Result<T, SaveError> {
+
+        match self.fields.get(index) {
+
+            Some(field) => match T::FromField(field) {
+
+                Some(value) => return Ok(value),
+                None => return Err(self.Error(&format!("Field {} is not valid: \"{}\".", index + 1, field))),
+            },
+            None => return Err(self.Error(&format!("Field {} is missing.", index + 1))),
+        }
+    }
+
+    // GetAll Function:
+    // Parameters:
+    // - start: Position of the first field to convert.
+    // Description: Converts every field from start to the end of the record.
+    pub fn GetAll<T: SaveField>(&self, start: usize) -> Result<Vec<T>, SaveError> {
+
+        let mut values: Vec<T> = Vec::new();
+        for index in start..self.fields.len() {
+
+            values.push(self.Get(index)?);
+        }
+
+        return Ok(values);
+    }
+
+    // Error Function:
+    // Parameters:
+    // - message: Description of the problem.
+    // Description: Creates an error pointing at this record.
+    pub fn Error(&self, message: &str) -> SaveError {
+
+        return SaveError::Format { line: self.line, message: String::from(message) };
+    }
+}
+
+// Reads the records of a save file in order.
+pub struct SaveReader {
+
+    lines: Vec<String>,
+    index: usize
+}
+
+impl SaveReader {
+
+    // New Function:
+    // Parameters:
+    // - text: The text of a save file.
+    // - header: First line that the file must have.
+    // Description: Checks the header and prepares to read the first record.
+    pub fn New(text: &str, header: &str) -> Result<SaveReader, SaveError> {
+
+        let lines: Vec<String> = text.lines().map(String::from).collect();
+        if lines.first().map(|line| line.as_str()) != Some(header) {
+
+            return Err(SaveError::Format { line: 1, message: format!("Expected a file starting with \"{}\".", header) });
+        }
+
+        return Ok(SaveReader { lines, index: 1 });
+    }
+
+    // Read Function:
+    // Parameters:
+    // - key: Name of the record that is expected next.
+    // Description: Reads the next record.
+    // Return: The record. Error if the file ended or the next record has a different key.
+    pub fn Read(&mut self, key: &str) -> Result<SaveRecord, SaveError> {
+
+        let line = self.index + 1;
+        let text = match self.lines.get(self.index) {
+
+            Some(text) => text,
+            None => return Err(SaveError::Format { line, message: format!("Expected \"{}\" but the file ended.", key) }),
+        };
+
+        let mut fields: Vec<String> = text.split('\t').map(String::from).collect();
+        if fields[0] != key {
+
+            return Err(SaveError::Format { line, message: format!("Expected \"{}\" but found \"{}\".", key, fields[0]) });
+        }
+
+        fields.remove(0);
+        self.index += 1;
+
+        return Ok(SaveRecord { line, fields });
+    }
+}
@@ -0,0 +1,57 @@
+// My modules.
+use super::{SaveField, SaveWriter, SaveReader, SaveError};
+
+#[test]
+fn FieldsReadBackWhatWasWritten() {
+
+    assert_eq!(u16::FromField(&42u16.ToField()), Some(42));
+    assert_eq!(bool::FromField(&true.ToField()), Some(true));
+    assert_eq!(String::FromField(&String::from("H ").ToField()), Some(String::from("H ")));
+    assert_eq!(<(usize, usize)>::FromField(&(3, 4).ToField()), Some((3, 4)));
+    assert_eq!(<(u8, u8, u8)>::FromField(&(255, 0, 127).ToField()), Some((255, 0, 127)));
+    assert_eq!(Option::<u16>::FromField(&Some(7u16).ToField()), Some(Some(7)));
+    assert_eq!(Option::<u16>::FromField(&None::<u16>.ToField()), Some(None));
+}
+
+#[test]
+fn BadFieldsAreRejected() {
+
+    assert_eq!(u8::FromField("300"), None);
+    assert_eq!(u16::FromField("-1"), None);
+    assert_eq!(bool::FromField("yes"), None);
+    assert_eq!(<(usize, usize)>::FromField("3"), None);
+    assert_eq!(<(u8, u8, u8)>::FromField("1,2"), None);
+    assert_eq!(Option::<u16>::FromField("x"), None);
+}
+
+#[test]
+fn RecordsPointAtTheirLine() {
+
+    let mut writer = SaveWriter::New("test 1");
+    writer.Write("size", &[String::from("6"), String::from("six")]);
+    let text = writer.Finish();
+
+    let mut reader = SaveReader::New(&text, "test 1").unwrap();
+    let record = reader.Read("size").unwrap();
+    assert_eq!(record.Get::<u8>(0), Ok(6));
+    assert!(matches!(record.Get::<u8>(1), Err(SaveError::Format { line: 2, .. })));
+    assert!(matches!(record.Get::<u8>(2), Err(SaveError::Format { line: 2, .. })));
+
+    // Reading past the end or reading the wrong record is an error.
+    assert!(matches!(reader.Read("size"), Err(SaveError::Format { line: 3, .. })));
+    let mut reader = SaveReader::New(&text, "test 1").unwrap();
+    assert!(matches!(reader.Read("turn"), Err(SaveError::Format { line: 2, .. })));
+
+    assert!(matches!(SaveReader::New(&text, "test 2"), Err(SaveError::Format { line: 1, .. })));
+}
+
+#[test]
+fn TabsCannotSplitAField() {
+
+    let mut writer = SaveWriter::New("test 1");
+    writer.Write("name", &[String::from("Ana\tBen\nCid")]);
+
+    let text = writer.Finish();
+    let mut reader = SaveReader::New(&text, "test 1").unwrap();
+    assert_eq!(reader.Read("name").unwrap().Get::<String>(0), Ok(String::from("Ana Ben Cid")));
+}
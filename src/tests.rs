@@ -0,0 +1,194 @@
+// My modules.
+use super::{Mode, CreateCoords, SplitOrder, TileName, DrawGameBoard, DrawBondRow, DrawPassScreen, DrawEmptyHand, BoardGap, TABLE_ROWS};
+use rust_fcgreen6::game_board::{GameBoard, TileState};
+use rust_fcgreen6::card_deck::Card;
+use rust_fcgreen6::action_log::ActionLog;
+use rust_fcgreen6::chance::Chance;
+use rust_fcgreen6::game::{Game, GameConfig, Side, UndoRule};
+use rust_fcgreen6::computer::Difficulty;
+
+// Corners Function:
+// Parameters:
+// - board: The board to look at.
+// Description: Returns the four corner tiles of a board.
+fn Corners(board: &GameBoard) -> [(usize, usize); 4] {
+
+    let (right, bottom) = (board.GetWidth() - 1, board.GetHeight() - 1);
+    return [(0, 0), (right, 0), (0, bottom), (right, bottom)];
+}
+
+// PlainText Function:
+// Parameters:
+// - line: A line of the screen.
+// Description: Removes the color codes from a line, leaving the characters that are shown.
+fn PlainText(line: &str) -> Vec<char> {
+
+    let mut plain: Vec<char> = Vec::new();
+    let mut in_code = false;
+    for character in line.chars() {
+
+        if character == '\u{1b}' {
+
+            in_code = true;
+        }
+        else if in_code {
+
+            in_code = character != 'm';
+        }
+        else {
+
+            plain.push(character);
+        }
+    }
+
+    return plain;
+}
+
+#[test]
+fn CoordsReadMultiDigitRows() {
+
+    assert_eq!(CreateCoords(String::from("A1")), (true, (0, 0)));
+    assert_eq!(CreateCoords(String::from("b4")), (true, (1, 3)));
+    assert_eq!(CreateCoords(String::from("J10")), (true, (9, 9)));
+    assert_eq!(CreateCoords(String::from("L12")), (true, (11, 11)));
+
+    // Rows are numbered from one and only hold digits.
+    for tile in ["A0", "A", "1A", "A1x", "A-1", "A+1", ""] {
+
+        assert!(!CreateCoords(String::from(tile)).0, "{} should not be a tile", tile);
+    }
+
+    assert_eq!(TileName((9, 9)), "J10");
+}
+
+#[test]
+fn CoordsPastTheBoardAreKept() {
+
+    // J10 is past the last row of a 10x4 board and past the last column of a 4x10 board.
+    let (valid, tile) = CreateCoords(String::from("J10"));
+    assert!(valid);
+    assert!(!GameBoard::New(10, 4).IsOnBoard(tile));
+    assert!(!GameBoard::New(4, 10).IsOnBoard(tile));
+
+    assert!(GameBoard::New(10, 4).IsOnBoard(CreateCoords(String::from("J4")).1));
+    assert!(GameBoard::New(4, 10).IsOnBoard(CreateCoords(String::from("D10")).1));
+}
+
+#[test]
+fn EdgeTilesOfNonSquareBoards() {
+
+    let oxygen = Card::FromName("O").unwrap().GetAtom(2);
+
+    for (width, height) in [(10, 4), (4, 10), (12, 3)] {
+
+        // An atom in each corner gets bond zones on the two neighbors that are on the board.
+        let mut board = GameBoard::New(width, height);
+        for (turn, corner) in Corners(&board).into_iter().enumerate() {
+
+            board.Bond(corner, &oxygen, turn as u8 + 1, &mut Chance::Seeded(1), None).unwrap();
+            board.EndTurn();
+        }
+
+        for (x, y) in Corners(&board) {
+
+            let inward_x = if x == 0 { 1 } else { x - 1 };
+            let inward_y = if y == 0 { 1 } else { y - 1 };
+            assert_eq!(board.GetTileState((inward_x, y)), TileState::Bond, "{}x{} at {},{}", width, height, x, y);
+            assert_eq!(board.GetTileState((x, inward_y)), TileState::Bond, "{}x{} at {},{}", width, height, x, y);
+        }
+
+        // Every line of the board lines up with the table, so the action log starts in the same column.
+        let mut screen: Vec<String> = Vec::new();
+        DrawGameBoard(&mut screen, &board, &ActionLog::default());
+        assert_eq!(screen.len(), (2 * height - 1).max(TABLE_ROWS.len()) + 1);
+
+        let log_column = TABLE_ROWS[0].0.len() + BoardGap(&board) + TABLE_ROWS[0].1.len() + 2;
+        for line in screen[..screen.len() - 1].iter() {
+
+            assert_eq!(PlainText(line).get(log_column), Some(&'|'), "{}x{}: {}", width, height, line);
+        }
+
+        // The last row is labeled with its number.
+        let last_row: String = PlainText(&screen[2 * (height - 1)]).into_iter().collect();
+        assert!(last_row.contains(&format!(" {}[", height)), "{}", last_row);
+    }
+}
+
+#[test]
+fn OrderIsTakenOutOfThePlayCommand() {
+
+    assert_eq!(SplitOrder("play O B3 --order 1"), Some((String::from("play O B3"), Some(1))));
+    assert_eq!(SplitOrder("play O --order 2 B3"), Some((String::from("play O B3"), Some(2))));
+    assert_eq!(SplitOrder("play O B3"), Some((String::from("play O B3"), None)));
+
+    // --order must be followed by a number.
+    assert_eq!(SplitOrder("play O B3 --order"), None);
+    assert_eq!(SplitOrder("play O B3 --order two"), None);
+}
+
+#[test]
+fn BondRowsShowTheOrder() {
+
+    let carbon = Card::FromName("C").unwrap().GetAtom(4);
+
+    // C forms a single bond with H, a double bond with O, and a triple bond with N below it.
+    for (partner, line) in [("H", " |  "), ("O", " ‖  "), ("N", " ⦀  ")] {
+
+        let card = Card::FromName(partner).unwrap();
+        let mut board = GameBoard::New(3, 3);
+        board.Bond((0, 0), &carbon, 1, &mut Chance::Seeded(1), None).unwrap();
+        board.EndTurn();
+        board.Bond((0, 1), &card.GetAtom(card.number_of_bonds), 2, &mut Chance::Seeded(1), None).unwrap();
+        board.EndTurn();
+
+        assert_eq!(DrawBondRow(&board, 0), format!("{}{}", line, " ".repeat(8)), "{}", partner);
+        assert_eq!(DrawBondRow(&board, 1), " ".repeat(12), "{}", partner);
+    }
+}
+
+#[test]
+fn HotseatNamesBothPlayers() {
+
+    assert_eq!(Mode::Hotseat.GetSideNames(), (String::from("Player 1"), String::from("Player 2")));
+    assert_eq!(Mode::Network.GetSideNames(), (String::from("Host"), String::from("Guest")));
+    assert_eq!(Mode::Computer(Difficulty::Easy).GetSideNames(), (Side::Player.GetName(), Side::Opponent.GetName()));
+}
+
+#[test]
+fn UndoAlwaysNeedsAComputer() {
+
+    for rule in [UndoRule::Off, UndoRule::Turn, UndoRule::Always] {
+
+        assert_eq!(Mode::Computer(Difficulty::Hard).GetUndoRule(rule), rule);
+    }
+
+    for mode in [Mode::Hotseat, Mode::Network] {
+
+        assert_eq!(mode.GetUndoRule(UndoRule::Always), UndoRule::Turn);
+        assert_eq!(mode.GetUndoRule(UndoRule::Turn), UndoRule::Turn);
+        assert_eq!(mode.GetUndoRule(UndoRule::Off), UndoRule::Off);
+    }
+}
+
+#[test]
+fn PassScreenHidesBothHands() {
+
+    let (player_name, opponent_name) = Mode::Hotseat.GetSideNames();
+    let game = Game::NewWithChance(&player_name, &opponent_name, GameConfig::default(), Chance::Seeded(3));
+
+    for side in [Side::Player, Side::Opponent] {
+
+        let screen = DrawPassScreen(&game, side);
+        for hand_side in [Side::Player, Side::Opponent] {
+
+            let hand = game.GetDeck(hand_side).FormatUpperHand();
+            assert!(screen.iter().all(|line| !line.contains(&hand)), "{}", hand);
+        }
+
+        // The hand is drawn empty, followed by the prompt for the incoming side.
+        let mut empty: Vec<String> = Vec::new();
+        DrawEmptyHand(&mut empty, game.GetBoard());
+        assert_eq!(screen[screen.len() - 1 - empty.len()..screen.len() - 1], empty[..]);
+        assert!(screen[screen.len() - 1].contains(&game.GetSideName(side)));
+    }
+}